@@ -0,0 +1,129 @@
+use crate::solution::Solver;
+
+/// Small deterministic pseudo-random number generator (SplitMix64), so that a
+/// seed yields the same puzzle input on every platform and toolchain.
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    /// Uniform number from `0` to `n - 1`; `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        return ((self.next_u64() as u128 * n as u128) >> 64) as u64;
+    }
+
+    /// Uniform number from `from` to `to`, both included.
+    pub fn between(&mut self, from: i64, to: i64) -> i64 {
+        return from + self.below((to - from) as u64 + 1) as i64;
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        return self.below(len as u64) as usize;
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        return self.below(denominator) < numerator;
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.index(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Puzzle input produced by a day's generator, with the answers of a reference
+/// implementation when they are cheap enough to compute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedInput {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>
+}
+
+impl GeneratedInput {
+    /// Solves the input and compares both parts with the known answers.
+    pub fn check(&self, solver: &dyn Solver) -> Result<(), String> {
+        let answers = solver.solve(&mut self.input.as_bytes()).map_err(|error| error.to_string())?;
+        for (part, expected, actual) in [(1, &self.part1, answers.part1), (2, &self.part2, answers.part2)] {
+            if let Some(expected) = expected {
+                if *expected != actual {
+                    return Err(format!("part {}: expected {}, got {}", part, expected, actual));
+                }
+            }
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{input::read_input, parse::ParseError, solution::Solution};
+    use std::io::BufRead;
+
+    #[test]
+    fn repeats_sequence_for_the_same_seed() {
+        let sequence = |seed| {
+            let mut rng = Rng::new(seed);
+            return (0..5).map(|_| rng.between(-3, 3)).collect::<Vec<i64>>();
+        };
+        assert_eq!(sequence(7), sequence(7));
+        assert_ne!(sequence(7), sequence(8));
+        assert!(sequence(7).iter().all(|n| (-3..=3).contains(n)));
+    }
+
+    #[test]
+    fn shuffles_into_a_permutation() {
+        let mut items = (0..10).collect::<Vec<u32>>();
+        Rng::new(1).shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+    }
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        type Parsed = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<String>, ParseError> {
+            return read_input(input);
+        }
+
+        fn part1(&self, lines: &Vec<String>) -> Result<usize, ParseError> {
+            return Ok(lines.len());
+        }
+
+        fn part2(&self, lines: &Vec<String>) -> Result<usize, ParseError> {
+            return Ok(lines.len() * 2);
+        }
+    }
+
+    #[test]
+    fn checks_known_answers_only() {
+        let generated = GeneratedInput { input: "a\nb".to_string(), part1: Some("2".to_string()), part2: None };
+        assert_eq!(generated.check(&LineCount), Ok(()));
+
+        let generated = GeneratedInput { part2: Some("5".to_string()), ..generated };
+        assert_eq!(generated.check(&LineCount), Err("part 2: expected 5, got 4".to_string()));
+    }
+}
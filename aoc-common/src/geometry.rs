@@ -0,0 +1,278 @@
+use std::{cmp, fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+/// Number type usable as a point coordinate.
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+
+                fn abs_diff(self, other: $t) -> $t {
+                    return if self > other { self - other } else { other - self };
+                }
+
+                fn checked_add(self, other: $t) -> Option<$t> {
+                    return <$t>::checked_add(self, other);
+                }
+
+                fn checked_sub(self, other: $t) -> Option<$t> {
+                    return <$t>::checked_sub(self, other);
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+/// Point (or vector) on a plane, with `y` growing downwards wherever a grid is involved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        return Point { x, y };
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn manhattan_distance(&self, other: &Point<T>) -> T {
+        return self.x.abs_diff(other.x) + self.y.abs_diff(other.y);
+    }
+
+    /// Number of king moves between the points, so 1 for every one of the eight neighbours.
+    pub fn chebyshev_distance(&self, other: &Point<T>) -> T {
+        return cmp::max(self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+    }
+
+    pub fn checked_add(&self, other: &Point<T>) -> Option<Point<T>> {
+        return Some(Point::new(self.x.checked_add(other.x)?, self.y.checked_add(other.y)?));
+    }
+
+    pub fn checked_sub(&self, other: &Point<T>) -> Option<Point<T>> {
+        return Some(Point::new(self.x.checked_sub(other.x)?, self.y.checked_sub(other.y)?));
+    }
+
+    /// Orthogonal neighbours in the order left, down, right, up, skipping those that would overflow.
+    pub fn neighbours4(&self) -> Vec<Point<T>> {
+        return [
+            self.x.checked_sub(T::ONE).map(|x| Point::new(x, self.y)),
+            self.y.checked_add(T::ONE).map(|y| Point::new(self.x, y)),
+            self.x.checked_add(T::ONE).map(|x| Point::new(x, self.y)),
+            self.y.checked_sub(T::ONE).map(|y| Point::new(self.x, y))
+        ].into_iter().flatten().collect();
+    }
+
+    /// Orthogonal and diagonal neighbours, row by row from the top left, skipping those that would overflow.
+    pub fn neighbours8(&self) -> Vec<Point<T>> {
+        let steps = |c: T| [c.checked_sub(T::ONE), Some(c), c.checked_add(T::ONE)];
+        return steps(self.y).into_iter().flatten()
+            .flat_map(|y| steps(self.x).into_iter().flatten().map(move |x| Point::new(x, y)))
+            .filter(|p| p != self)
+            .collect();
+    }
+
+    /// Point one step closer to `target` along each axis that differs.
+    pub fn step_towards(&self, target: &Point<T>) -> Point<T> {
+        let step = |from: T, to: T| match from.cmp(&to) {
+            cmp::Ordering::Less => from + T::ONE,
+            cmp::Ordering::Greater => from - T::ONE,
+            cmp::Ordering::Equal => from
+        };
+        return Point::new(step(self.x, target.x), step(self.y, target.y));
+    }
+
+    /// Every point on a horizontal, vertical or diagonal segment, from `self` to `to` inclusive.
+    pub fn line_to(&self, to: &Point<T>) -> Vec<Point<T>> {
+        let mut line = vec![*self];
+        let mut current = *self;
+        while current != *to {
+            current = current.step_towards(to);
+            line.push(current);
+        }
+        return line;
+    }
+
+    /// Converts the coordinates to another number type, or `None` if they do not fit.
+    pub fn convert<U: TryFrom<T>>(&self) -> Option<Point<U>> {
+        return Some(Point::new(U::try_from(self.x).ok()?, U::try_from(self.y).ok()?));
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        return Point::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        return Point::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        return Point::new(self.x * factor, self.y * factor);
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        return Point::new(-self.x, -self.y);
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        return Point::new(x, y);
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> (T, T) {
+        return (point.x, point.y);
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{},{}", self.x, self.y);
+    }
+}
+
+/// Axis-aligned rectangle with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds<T> {
+    pub min: Point<T>,
+    pub max: Point<T>
+}
+
+impl<T: Coordinate> Bounds<T> {
+    pub fn new(min: Point<T>, max: Point<T>) -> Bounds<T> {
+        return Bounds { min, max };
+    }
+
+    /// Smallest bounds containing all the points, or `None` if there are none.
+    pub fn enclosing<'a>(points: impl IntoIterator<Item = &'a Point<T>>) -> Option<Bounds<T>> where T: 'a {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        return Some(points.fold(Bounds::new(first, first), |bounds, p| bounds.expanded_to(p)));
+    }
+
+    pub fn expanded_to(&self, point: &Point<T>) -> Bounds<T> {
+        return Bounds::new(
+            Point::new(cmp::min(self.min.x, point.x), cmp::min(self.min.y, point.y)),
+            Point::new(cmp::max(self.max.x, point.x), cmp::max(self.max.y, point.y))
+        );
+    }
+
+    pub fn contains(&self, point: &Point<T>) -> bool {
+        return self.min.x <= point.x && point.x <= self.max.x
+            && self.min.y <= point.y && point.y <= self.max.y;
+    }
+
+    pub fn width(&self) -> T {
+        return self.max.x - self.min.x + T::ONE;
+    }
+
+    pub fn height(&self) -> T {
+        return self.max.y - self.min.y + T::ONE;
+    }
+
+    /// Nearest point inside the bounds.
+    pub fn clamp(&self, point: &Point<T>) -> Point<T> {
+        return Point::new(point.x.clamp(self.min.x, self.max.x), point.y.clamp(self.min.y, self.max.y));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(Point::new(8, 7).manhattan_distance(&Point::new(2, 10)), 9);
+        assert_eq!(Point::new(8, 7).chebyshev_distance(&Point::new(2, 10)), 6);
+        assert_eq!(Point::new(3usize, 1).manhattan_distance(&Point::new(1, 4)), 5);
+    }
+
+    #[test]
+    fn combines_points_arithmetically() {
+        let mut p = Point::new(1, -2) + Point::new(3, 4) * 2;
+        assert_eq!(p, Point::new(7, 6));
+        p -= Point::new(7, 7);
+        assert_eq!(-p, Point::new(0, 1));
+        assert_eq!(Point::new(0usize, 1).checked_sub(&Point::new(1, 0)), None);
+    }
+
+    #[test]
+    fn lists_neighbours_without_overflowing() {
+        assert_eq!(Point::new(0usize, 0).neighbours4(), vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(Point::new(5, 5).neighbours4().len(), 4);
+        assert_eq!(Point::new(0usize, 1).neighbours8(), vec![Point::new(0, 0), Point::new(1, 0), Point::new(1, 1), Point::new(0, 2), Point::new(1, 2)]);
+        assert_eq!(Point::new(5, 5).neighbours8().len(), 8);
+    }
+
+    #[test]
+    fn walks_lines_between_points() {
+        assert_eq!(Point::new(2usize, 3).line_to(&Point::new(2, 1)), vec![Point::new(2, 3), Point::new(2, 2), Point::new(2, 1)]);
+        assert_eq!(Point::new(4, 0).line_to(&Point::new(6, 2)), vec![Point::new(4, 0), Point::new(5, 1), Point::new(6, 2)]);
+        assert_eq!(Point::new(0, 0).step_towards(&Point::new(-3, 2)), Point::new(-1, 1));
+    }
+
+    #[test]
+    fn converts_between_types() {
+        assert_eq!(Point::from((1, 2)), Point::new(1, 2));
+        assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+        assert_eq!(Point::new(3isize, 4).convert::<usize>(), Some(Point::new(3usize, 4)));
+        assert_eq!(Point::new(-3isize, 4).convert::<usize>(), None);
+    }
+
+    #[test]
+    fn encloses_points_in_bounds() {
+        let bounds = Bounds::enclosing(&[Point::new(3, 1), Point::new(-1, 4), Point::new(2, 2)]).unwrap();
+        assert_eq!(bounds, Bounds::new(Point::new(-1, 1), Point::new(3, 4)));
+        assert_eq!((bounds.width(), bounds.height()), (5, 4));
+        assert!(bounds.contains(&Point::new(0, 2)));
+        assert_eq!(bounds.clamp(&Point::new(10, -10)), Point::new(3, 1));
+        assert_eq!(Bounds::<i32>::enclosing(&[]), None);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{geometry::Point, parse::{Line, ParseError}};
+
+/// Rectangular grid of cells addressed by `(x, y)`, with `y` growing downwards.
+pub struct Grid<T> {
+    grid: Vec<Vec<T>>
+}
+
+impl<T> Grid<T> {
+    pub fn new(grid: Vec<Vec<T>>) -> Grid<T> {
+        return Grid { grid };
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        return Grid { grid: vec![vec![value; width]; height] };
+    }
+
+    pub fn height(&self) -> usize {
+        return self.grid.len();
+    }
+
+    pub fn width(&self) -> usize {
+        return self.grid.first().map(|row| row.len()).unwrap_or(0);
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        return self.grid.get(y).map(|row| x < row.len()).unwrap_or(false);
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> &T {
+        return self.grid.get(y).and_then(|row| row.get(x)).unwrap();
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        self.grid.get_mut(y).unwrap()[x] = value;
+    }
+
+    pub fn rows(&self) -> &[Vec<T>] {
+        return &self.grid;
+    }
+
+    pub fn get_at(&self, point: &Point<usize>) -> &T {
+        return self.get(point.x, point.y);
+    }
+
+    pub fn coords(&self) -> Vec<Point<usize>> {
+        return (0..self.grid.len())
+            .flat_map(|y| (0..self.grid[y].len()).map(move |x| Point::new(x, y)))
+            .collect();
+    }
+
+    pub fn find_coords(&self, predicate: impl Fn(&T) -> bool) -> Vec<Point<usize>> {
+        return self.coords().into_iter()
+            .filter(|p| predicate(self.get_at(p)))
+            .collect();
+    }
+
+    /// Up to four orthogonal neighbours that lie inside the grid.
+    pub fn neighbours(&self, point: &Point<usize>) -> Vec<Point<usize>> {
+        return point.neighbours4().into_iter()
+            .filter(|p| self.contains(p.x, p.y))
+            .collect();
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        return Grid::new(self.grid.iter()
+            .map(|row| row.iter().map(&f).collect())
+            .collect());
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn row(&self, y: usize) -> Vec<T> {
+        return self.grid[y].to_vec();
+    }
+
+    pub fn column(&self, x: usize) -> Vec<T> {
+        return self.grid.iter().map(|row| row[x].clone()).collect();
+    }
+}
+
+/// Parses a rectangular grid with one cell per character, rejecting unknown
+/// characters and rows of differing widths.
+pub fn parse_grid<T>(lines: &[Line], expected: &str, parse_cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+    let width = lines.first().map(|line| line.text.chars().count()).unwrap_or(0);
+    let mut grid = Vec::new();
+    for line in lines {
+        let row = line.text.chars()
+            .enumerate()
+            .map(|(i, c)| parse_cell(c).ok_or_else(|| line.error_at(i + 1, expected)))
+            .collect::<Result<Vec<T>, ParseError>>()?;
+        if row.len() != width {
+            return Err(line.error_at(row.len().min(width) + 1, format!("a row of {} cells", width)));
+        }
+        grid.push(row);
+    }
+    return Ok(Grid::new(grid));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::number_lines;
+
+    fn sample() -> Grid<u8> {
+        return Grid::new(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    }
+
+    #[test]
+    fn reads_dimensions_rows_and_columns() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(1), vec![4, 5, 6]);
+        assert_eq!(grid.column(2), vec![3, 6]);
+        assert_eq!(*grid.get(1, 1), 5);
+    }
+
+    #[test]
+    fn lists_neighbours_inside_bounds() {
+        let grid = sample();
+        assert_eq!(grid.neighbours(&Point::new(0, 0)), vec![Point::new(0, 1), Point::new(1, 0)]);
+        assert_eq!(grid.neighbours(&Point::new(1, 1)).len(), 3);
+    }
+
+    #[test]
+    fn finds_matching_coords() {
+        assert_eq!(sample().find_coords(|v| v % 2 == 0), vec![Point::new(1, 0), Point::new(0, 1), Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn parses_rectangular_grid() {
+        let lines = vec!["12".to_string(), "34".to_string()];
+        let grid = parse_grid(&number_lines(&lines), "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(grid.row(1), vec![3, 4]);
+    }
+
+    #[test]
+    fn rejects_unknown_cells_and_ragged_rows() {
+        let lines = vec!["12".to_string(), "3x".to_string()];
+        assert_eq!(parse_grid(&number_lines(&lines), "a digit", |c| c.to_digit(10)).err(), Some(ParseError::new(2, 2, "a digit")));
+
+        let lines = vec!["12".to_string(), "345".to_string()];
+        assert_eq!(parse_grid(&number_lines(&lines), "a digit", |c| c.to_digit(10)).err(), Some(ParseError::new(2, 3, "a row of 2 cells")));
+    }
+}
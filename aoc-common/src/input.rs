@@ -0,0 +1,63 @@
+use std::io::BufRead;
+use crate::parse::ParseError;
+
+/// Reads the whole puzzle input as a list of lines.
+pub fn read_input(input: &mut dyn BufRead) -> Result<Vec<String>, ParseError> {
+    return input.lines()
+        .enumerate()
+        .map(|(i, line)| line.map_err(|e| ParseError::new(i + 1, 1, format!("readable UTF-8 text ({})", e))))
+        .collect();
+}
+
+/// Reads only the first line of the puzzle input, or an empty string if there is none.
+pub fn read_first_line(input: &mut dyn BufRead) -> Result<String, ParseError> {
+    return read_input(input).map(|lines| lines.into_iter().next().unwrap_or_default());
+}
+
+/// Splits lines into sections separated by blank lines.
+pub fn split_sections<T: AsRef<str>>(lines: &[T]) -> Vec<&[T]> {
+    return lines.split(|line| line.as_ref().is_empty()).collect();
+}
+
+/// Reads the puzzle input as a list of sections separated by blank lines.
+pub fn read_sections(input: &mut dyn BufRead) -> Result<Vec<Vec<String>>, ParseError> {
+    return Ok(split_sections(&read_input(input)?).iter()
+        .map(|section| section.to_vec())
+        .collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{number_lines, Line};
+
+    #[test]
+    fn reads_lines() {
+        assert_eq!(read_input(&mut "a\nb\n".as_bytes()), Ok(vec!["a".to_string(), "b".to_string()]));
+    }
+
+    #[test]
+    fn reports_line_that_is_not_valid_utf8() {
+        let error = read_input(&mut &b"a\n\xff\n"[..]).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn reads_first_line() {
+        assert_eq!(read_first_line(&mut "abc\ndef".as_bytes()), Ok("abc".to_string()));
+        assert_eq!(read_first_line(&mut "".as_bytes()), Ok("".to_string()));
+    }
+
+    #[test]
+    fn splits_sections_on_blank_lines() {
+        let sections = read_sections(&mut "1\n2\n\n3\n\n4\n5".as_bytes()).unwrap();
+        assert_eq!(sections, vec![vec!["1", "2"], vec!["3"], vec!["4", "5"]]);
+    }
+
+    #[test]
+    fn keeps_line_numbers_in_sections() {
+        let lines = read_input(&mut "1\n\n3".as_bytes()).unwrap();
+        let numbered = number_lines(&lines);
+        assert_eq!(split_sections(&numbered)[1], [Line::new(3, "3")]);
+    }
+}
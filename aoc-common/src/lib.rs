@@ -0,0 +1,11 @@
+//! Building blocks shared by all the puzzle solutions: input reading,
+//! section splitting, parse errors, points, two-dimensional grids, the
+//! `Solution` trait, seeded input generation and PPM/SVG rendering.
+
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod solution;
@@ -0,0 +1,120 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Malformed puzzle input, pointing at the offending place.
+///
+/// Lines and columns are 1-based; the column counts characters, not bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> ParseError {
+        return ParseError { line, column, expected: expected.into() };
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected);
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of puzzle input together with its 1-based line number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Line<'a> {
+        return Line { number, text };
+    }
+
+    /// 1-based column at which `part` starts, if `part` was sliced out of this line.
+    pub fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            return self.text[..offset].chars().count() + 1;
+        }
+        else {
+            return 1;
+        }
+    }
+
+    /// Column just past the last character of the line.
+    pub fn end_column(&self) -> usize {
+        return self.text.chars().count() + 1;
+    }
+
+    pub fn error_at(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        return ParseError::new(self.number, column, expected);
+    }
+
+    /// Error pointing at the start of `part`, a slice of this line.
+    pub fn error_on(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        return self.error_at(self.column_of(part), expected);
+    }
+
+    /// Parses `part`, a slice of this line, reporting its position when it is malformed.
+    pub fn parse<T: FromStr>(&self, part: &str, expected: &str) -> Result<T, ParseError> {
+        return part.parse::<T>().map_err(|_| self.error_on(part, expected));
+    }
+}
+
+impl AsRef<str> for Line<'_> {
+    fn as_ref(&self) -> &str {
+        return self.text;
+    }
+}
+
+/// Numbers lines starting from 1.
+pub fn number_lines(lines: &[String]) -> Vec<Line<'_>> {
+    return lines.iter()
+        .enumerate()
+        .map(|(i, text)| Line::new(i + 1, text))
+        .collect();
+}
+
+/// Error for input that ends where `expected` should have followed `last`.
+pub fn unexpected_end(last: Option<&Line>, expected: impl Into<String>) -> ParseError {
+    return ParseError::new(last.map(|line| line.number + 1).unwrap_or(1), 1, expected);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_column_of_slice() {
+        let line = Line::new(4, "move 1 from 2 to x");
+        let (_, destination) = line.text.rsplit_once(' ').unwrap();
+        assert_eq!(line.column_of(destination), 18);
+        assert_eq!(line.parse::<usize>(destination, "a stack number"), Err(ParseError::new(4, 18, "a stack number")));
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let line = Line::new(1, "ąę 12");
+        assert_eq!(line.column_of(&line.text[5..]), 4);
+        assert_eq!(line.parse::<u32>(&line.text[5..], "a number"), Ok(12));
+    }
+
+    #[test]
+    fn formats_error_with_location() {
+        assert_eq!(ParseError::new(3, 7, "a digit").to_string(), "line 3, column 7: expected a digit");
+    }
+
+    #[test]
+    fn numbers_lines_from_one() {
+        let lines = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(number_lines(&lines), vec![Line::new(1, "a"), Line::new(2, "b")]);
+        assert_eq!(unexpected_end(number_lines(&lines).last(), "c"), ParseError::new(3, 1, "c"));
+    }
+}
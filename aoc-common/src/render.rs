@@ -0,0 +1,126 @@
+use std::{fmt, io::{self, Write}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8
+}
+
+impl Colour {
+    pub const BLACK: Colour = Colour::rgb(0, 0, 0);
+    pub const WHITE: Colour = Colour::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Colour {
+        return Colour { r, g, b };
+    }
+
+    /// Colour `step / steps` of the way from `self` to `other`.
+    pub fn mix(&self, other: &Colour, step: usize, steps: usize) -> Colour {
+        let channel = |from: u8, to: u8| {
+            let (from, to, step, steps) = (from as isize, to as isize, step.min(steps) as isize, steps.max(1) as isize);
+            return (from + (to - from) * step / steps) as u8;
+        };
+        return Colour::rgb(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b));
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b);
+    }
+}
+
+/// A puzzle state drawn as a rectangle of coloured cells, `(0, 0)` being the top left one.
+///
+/// Each cell becomes a `scale` by `scale` square of pixels in the exported images.
+pub trait Render {
+    fn width(&self) -> usize;
+
+    fn height(&self) -> usize;
+
+    fn colour(&self, x: usize, y: usize) -> Colour;
+
+    /// Writes a binary (P6) portable pixmap.
+    fn write_ppm(&self, scale: usize, out: &mut dyn Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width() * scale, self.height() * scale)?;
+        for y in 0..self.height() {
+            let row = (0..self.width())
+                .flat_map(|x| {
+                    let colour = self.colour(x, y);
+                    return [colour.r, colour.g, colour.b].repeat(scale);
+                })
+                .collect::<Vec<u8>>();
+            for _ in 0..scale {
+                out.write_all(&row)?;
+            }
+        }
+        return Ok(());
+    }
+
+    /// Writes an SVG image with one rectangle per run of equally coloured cells in a row.
+    fn write_svg(&self, scale: usize, out: &mut dyn Write) -> io::Result<()> {
+        writeln!(out, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">",
+            self.width() * scale, self.height() * scale, self.width(), self.height())?;
+        for y in 0..self.height() {
+            let mut x = 0;
+            while x < self.width() {
+                let colour = self.colour(x, y);
+                let run = (x..self.width()).take_while(|x| self.colour(*x, y) == colour).count();
+                writeln!(out, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>", x, y, run, colour)?;
+                x += run;
+            }
+        }
+        return writeln!(out, "</svg>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Checkerboard;
+
+    impl Render for Checkerboard {
+        fn width(&self) -> usize {
+            return 3;
+        }
+
+        fn height(&self) -> usize {
+            return 2;
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            return if (x + y).is_multiple_of(2) || x == 2 { Colour::BLACK } else { Colour::WHITE };
+        }
+    }
+
+    #[test]
+    fn mixes_colours() {
+        assert_eq!(Colour::BLACK.mix(&Colour::rgb(200, 100, 0), 1, 4), Colour::rgb(50, 25, 0));
+        assert_eq!(Colour::WHITE.mix(&Colour::BLACK, 7, 4), Colour::BLACK);
+        assert_eq!(Colour::rgb(255, 16, 1).to_string(), "#ff1001");
+    }
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let mut out = Vec::new();
+        Checkerboard.write_ppm(2, &mut out).unwrap();
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        assert_eq!(out.len(), header.len() + 6 * 4 * 3);
+        assert_eq!(&out[header.len()..header.len() + 9], &[0, 0, 0, 0, 0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn writes_svg_with_merged_runs() {
+        let mut out = Vec::new();
+        Checkerboard.write_svg(10, &mut out).unwrap();
+        let svg = String::from_utf8(out).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\" viewBox=\"0 0 3 2\""));
+        assert!(svg.contains("<rect x=\"1\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("<rect x=\"1\" y=\"1\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 5);
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{fmt::Display, io::BufRead};
+use crate::parse::ParseError;
+
+/// A puzzle solved in typed stages: the input is parsed once and both parts
+/// are answered from the parsed form.
+///
+/// Puzzle parameters that are not part of the input (number of knots, rounds,
+/// target row, ...) live on the implementing type, with `Default` giving the
+/// values of the actual puzzle.
+pub trait Solution {
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Self::Parsed, ParseError>;
+
+    /// Fails when the parsed input has no answer for this part only.
+    fn part1(&self, parsed: &Self::Parsed) -> Result<Self::Answer1, ParseError>;
+
+    /// Fails when the parsed input has no answer for this part only.
+    fn part2(&self, parsed: &Self::Parsed) -> Result<Self::Answer2, ParseError>;
+}
+
+/// Type-erased view of a [`Solution`], so that days with different parsed
+/// and answer types can be listed and run together.
+pub trait Solver {
+    /// Parses the input once and answers both parts.
+    fn solve(&self, input: &mut dyn BufRead) -> Result<Answers, ParseError>;
+
+    /// Parses the input and answers a single part, or `None` if there is no such part.
+    fn solve_part(&self, input: &mut dyn BufRead, part: u8) -> Option<Result<String, ParseError>>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String
+}
+
+impl<S: Solution> Solver for S {
+    fn solve(&self, input: &mut dyn BufRead) -> Result<Answers, ParseError> {
+        let parsed = self.parse(input)?;
+        return Ok(Answers {
+            part1: self.part1(&parsed)?.to_string(),
+            part2: self.part2(&parsed)?.to_string()
+        });
+    }
+
+    fn solve_part(&self, input: &mut dyn BufRead, part: u8) -> Option<Result<String, ParseError>> {
+        return match part {
+            1 => Some(self.parse(input).and_then(|parsed| self.part1(&parsed)).map(|answer| answer.to_string())),
+            2 => Some(self.parse(input).and_then(|parsed| self.part2(&parsed)).map(|answer| answer.to_string())),
+            _ => None
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::read_input;
+
+    struct LineStats;
+
+    impl Solution for LineStats {
+        type Parsed = Vec<String>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<String>, ParseError> {
+            return read_input(input);
+        }
+
+        fn part1(&self, lines: &Vec<String>) -> Result<usize, ParseError> {
+            return Ok(lines.len());
+        }
+
+        fn part2(&self, lines: &Vec<String>) -> Result<String, ParseError> {
+            if lines.is_empty() {
+                return Err(ParseError::new(1, 1, "a line"));
+            }
+            return Ok(lines.concat());
+        }
+    }
+
+    #[test]
+    fn solves_both_parts_from_one_parse() {
+        let solver: Box<dyn Solver> = Box::new(LineStats);
+        assert_eq!(solver.solve(&mut "ab\ncd".as_bytes()), Ok(Answers { part1: "2".to_string(), part2: "abcd".to_string() }));
+    }
+
+    #[test]
+    fn solves_single_part() {
+        assert_eq!(LineStats.solve_part(&mut "ab\ncd".as_bytes(), 2), Some(Ok("abcd".to_string())));
+        assert_eq!(LineStats.solve_part(&mut "ab\ncd".as_bytes(), 3), None);
+    }
+
+    #[test]
+    fn fails_only_the_part_without_answer() {
+        assert_eq!(LineStats.solve_part(&mut "".as_bytes(), 1), Some(Ok("0".to_string())));
+        assert_eq!(LineStats.solve_part(&mut "".as_bytes(), 2), Some(Err(ParseError::new(1, 1, "a line"))));
+        assert_eq!(LineStats.solve(&mut "".as_bytes()), Err(ParseError::new(1, 1, "a line")));
+    }
+}
@@ -0,0 +1,365 @@
+use std::{env, fs::File, io::{self, BufRead, BufReader, BufWriter, IsTerminal, Write}, path::Path, process, time::Instant};
+use tracing_subscriber::EnvFilter;
+
+mod manifest;
+mod registry;
+mod runner;
+
+const USAGE: &str = "usage: aoc run --day <1-15> [--part <1|2>] [--input <path>] [--log <filter>]\n       aoc generate --day <1-15> [--seed <number>]\n       aoc verify [--manifest <path>] [--log <filter>]\n       aoc render --day <8|9|12|14|15> --output <file.ppm|file.svg> [--input <path>] [--scale <pixels>]\n       aoc list\n\n<filter> selects diagnostics to print on stderr, e.g. `day11=trace` or `debug`;\nit defaults to the AOC_LOG environment variable and is off if neither is set.";
+
+const DEFAULT_MANIFEST: &str = "answers.toml";
+const LOG_VARIABLE: &str = "AOC_LOG";
+const DEFAULT_SCALE: usize = 4;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::List => list(),
+        Command::Run(run_args) => run(run_args),
+        Command::Generate(generate_args) => generate(generate_args),
+        Command::Verify(verify_args) => verify(verify_args),
+        Command::Render(render_args) => render(render_args)
+    }
+}
+
+fn list() {
+    for entry in registry::all() {
+        println!("{:>2} {}", entry.day, entry.title);
+    }
+}
+
+/// Prints the solvers' tracing events on stderr if a filter is given or set in `AOC_LOG`.
+fn init_logging(log: &Option<String>) {
+    let filter = match log.clone().or_else(|| env::var(LOG_VARIABLE).ok()) {
+        Some(filter) => filter,
+        None => return
+    };
+    match EnvFilter::try_new(&filter) {
+        Ok(filter) => tracing_subscriber::fmt()
+            .with_env_filter(filter)
+            .with_writer(io::stderr)
+            .with_ansi(io::stderr().is_terminal())
+            .init(),
+        Err(error) => {
+            eprintln!("invalid log filter {}: {}", filter, error);
+            process::exit(2);
+        }
+    }
+}
+
+/// Reads the given file, or stdin if there is none.
+fn open_input(input: &Option<String>) -> Box<dyn BufRead> {
+    return match input {
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(error) => {
+                eprintln!("cannot open {}: {}", path, error);
+                process::exit(1);
+            }
+        },
+        None => Box::new(BufReader::new(io::stdin()))
+    };
+}
+
+fn run(run_args: RunArgs) {
+    init_logging(&run_args.log);
+    let mut input = open_input(&run_args.input);
+
+    let start = Instant::now();
+    let result = match run_args.part {
+        Some(part) => runner::run(run_args.day, part, &mut input),
+        None => runner::run_both(run_args.day, &mut input)
+            .map(|answers| format!("Part 1: {}\nPart 2: {}", answers.part1, answers.part2))
+    };
+
+    match result {
+        Ok(answer) => {
+            println!("{}", answer);
+            println!("Elapsed: {:?}", start.elapsed());
+        },
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    }
+}
+
+/// Prints a generated input on stdout and the reference answers, when known, on stderr.
+fn generate(generate_args: GenerateArgs) {
+    let entry = match registry::find(generate_args.day) {
+        Some(entry) => entry,
+        None => {
+            eprintln!("day {} is not solved yet", generate_args.day);
+            process::exit(1);
+        }
+    };
+
+    let generated = (entry.generate)(generate_args.seed);
+    println!("{}", generated.input);
+    for (part, answer) in [(1, generated.part1), (2, generated.part2)] {
+        if let Some(answer) = answer {
+            eprintln!("Part {}: {}", part, answer);
+        }
+    }
+}
+
+/// Checks every answer of the manifest and prints a table of results, exiting with 1 if any fails.
+fn verify(verify_args: VerifyArgs) {
+    init_logging(&verify_args.log);
+    let manifest = match manifest::load(Path::new(&verify_args.manifest)) {
+        Ok(manifest) => manifest,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    };
+
+    let mut failures = Vec::new();
+    println!("{:>3} {:>4}  {:<24} {:>12}  result", "day", "part", "input", "time");
+    for answer in manifest.answers.iter() {
+        let verification = runner::verify(answer);
+        let result = match &verification.actual {
+            _ if verification.passed(answer) => "ok",
+            Ok(_) => "FAILED",
+            Err(_) => "ERROR"
+        };
+        let input = answer.input.display().to_string();
+        println!("{:>3} {:>4}  {:<24} {:>12}  {}", answer.day, answer.part, input, format!("{:.2?}", verification.elapsed), result);
+        if !verification.passed(answer) {
+            failures.push((answer, verification));
+        }
+    }
+
+    for (answer, verification) in failures.iter() {
+        println!("\nday {} part {} ({}):", answer.day, answer.part, answer.input.display());
+        match &verification.actual {
+            Ok(actual) => println!("expected:\n{}\ngot:\n{}", answer.expected, actual),
+            Err(message) => println!("{}", message)
+        }
+    }
+
+    println!("\n{} passed, {} failed", manifest.answers.len() - failures.len(), failures.len());
+    if !failures.is_empty() {
+        process::exit(1);
+    }
+}
+
+/// Writes a picture of the puzzle as PPM or SVG, depending on the extension of the output file.
+fn render(render_args: RenderArgs) {
+    let picture = match registry::find(render_args.day).map(|entry| entry.picture) {
+        Some(Some(picture)) => picture,
+        Some(None) => {
+            eprintln!("day {} cannot be rendered", render_args.day);
+            process::exit(1);
+        },
+        None => {
+            eprintln!("day {} is not solved yet", render_args.day);
+            process::exit(1);
+        }
+    };
+
+    let picture = match picture(&mut open_input(&render_args.input)) {
+        Ok(picture) => picture,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+
+    let written = File::create(&render_args.output).and_then(|file| {
+        let mut out = BufWriter::new(file);
+        match render_args.format {
+            ImageFormat::Ppm => picture.write_ppm(render_args.scale, &mut out)?,
+            ImageFormat::Svg => picture.write_svg(render_args.scale, &mut out)?
+        }
+        return out.flush();
+    });
+    if let Err(error) = written {
+        eprintln!("cannot write {}: {}", render_args.output, error);
+        process::exit(1);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run(RunArgs),
+    Generate(GenerateArgs),
+    Verify(VerifyArgs),
+    Render(RenderArgs),
+    List
+}
+
+#[derive(Debug, PartialEq)]
+struct RunArgs {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+    log: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+struct GenerateArgs {
+    day: u8,
+    seed: u64
+}
+
+#[derive(Debug, PartialEq)]
+struct VerifyArgs {
+    manifest: String,
+    log: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+struct RenderArgs {
+    day: u8,
+    input: Option<String>,
+    output: String,
+    format: ImageFormat,
+    scale: usize
+}
+
+#[derive(Debug, PartialEq)]
+enum ImageFormat {
+    Ppm,
+    Svg
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(String::as_str) {
+        Some("list") => return Ok(Command::List),
+        Some(command @ ("run" | "generate" | "verify" | "render")) => command,
+        Some(command) => return Err(format!("unknown command: {}", command)),
+        None => return Err("missing command".to_string())
+    };
+
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut seed = None;
+    let mut manifest = None;
+    let mut log = None;
+    let mut output = None;
+    let mut scale = None;
+
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or(format!("missing value for {}", flag))?;
+        match (command, flag.as_str()) {
+            ("run" | "generate" | "render", "--day") => day = Some(parse_number(flag, value)?),
+            ("run", "--part") => part = Some(parse_number(flag, value)?),
+            ("run" | "render", "--input") => input = Some(value.to_string()),
+            ("generate", "--seed") => seed = Some(parse_number(flag, value)?),
+            ("verify", "--manifest") => manifest = Some(value.to_string()),
+            ("run" | "verify", "--log") => log = Some(value.to_string()),
+            ("render", "--output") => output = Some(value.to_string()),
+            ("render", "--scale") => scale = Some(parse_number(flag, value)?),
+            _ => return Err(format!("unknown option: {}", flag))
+        }
+    }
+
+    if command == "verify" {
+        return Ok(Command::Verify(VerifyArgs { manifest: manifest.unwrap_or(DEFAULT_MANIFEST.to_string()), log }));
+    }
+
+    let day = day.ok_or("missing --day")?;
+    if command == "generate" {
+        return Ok(Command::Generate(GenerateArgs { day, seed: seed.unwrap_or(0) }));
+    }
+
+    if command == "render" {
+        let output: String = output.ok_or("missing --output")?;
+        let format = match Path::new(&output).extension().and_then(|extension| extension.to_str()) {
+            Some("ppm") => ImageFormat::Ppm,
+            Some("svg") => ImageFormat::Svg,
+            _ => return Err(format!("unsupported image format: {}", output))
+        };
+        let scale = match scale.unwrap_or(DEFAULT_SCALE) {
+            0 => return Err("invalid value for --scale: 0".to_string()),
+            scale => scale
+        };
+        return Ok(Command::Render(RenderArgs { day, input, output, format, scale }));
+    }
+
+    return Ok(Command::Run(RunArgs {
+        day,
+        part,
+        input,
+        log
+    }));
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    return value.parse::<T>().map_err(|_| format!("invalid value for {}: {}", flag, value));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_args, Command, GenerateArgs, ImageFormat, RenderArgs, RunArgs, VerifyArgs};
+
+    fn args(line: &str) -> Vec<String> {
+        return line.split(' ').map(String::from).collect();
+    }
+
+    #[test]
+    fn parses_run_command() {
+        assert_eq!(parse_args(&args("run --day 12 --part 2 --input day12/day.input")),
+            Ok(Command::Run(RunArgs { day: 12, part: Some(2), input: Some("day12/day.input".to_string()), log: None })));
+        assert_eq!(parse_args(&args("run --part 1 --day 3")),
+            Ok(Command::Run(RunArgs { day: 3, part: Some(1), input: None, log: None })));
+        assert_eq!(parse_args(&args("run --day 3")),
+            Ok(Command::Run(RunArgs { day: 3, part: None, input: None, log: None })));
+        assert_eq!(parse_args(&args("run --day 11 --log day11=trace")),
+            Ok(Command::Run(RunArgs { day: 11, part: None, input: None, log: Some("day11=trace".to_string()) })));
+    }
+
+    #[test]
+    fn parses_generate_command() {
+        assert_eq!(parse_args(&args("generate --day 7 --seed 42")),
+            Ok(Command::Generate(GenerateArgs { day: 7, seed: 42 })));
+        assert_eq!(parse_args(&args("generate --day 7")),
+            Ok(Command::Generate(GenerateArgs { day: 7, seed: 0 })));
+        assert!(parse_args(&args("generate --day 7 --part 1")).is_err());
+    }
+
+    #[test]
+    fn parses_verify_command() {
+        assert_eq!(parse_args(&args("verify")),
+            Ok(Command::Verify(VerifyArgs { manifest: "answers.toml".to_string(), log: None })));
+        assert_eq!(parse_args(&args("verify --manifest inputs/answers.toml")),
+            Ok(Command::Verify(VerifyArgs { manifest: "inputs/answers.toml".to_string(), log: None })));
+        assert!(parse_args(&args("verify --day 1")).is_err());
+    }
+
+    #[test]
+    fn parses_render_command() {
+        assert_eq!(parse_args(&args("render --day 14 --input day14/day.input --output cave.svg --scale 2")),
+            Ok(Command::Render(RenderArgs { day: 14, input: Some("day14/day.input".to_string()), output: "cave.svg".to_string(), format: ImageFormat::Svg, scale: 2 })));
+        assert_eq!(parse_args(&args("render --output forest.ppm --day 8")),
+            Ok(Command::Render(RenderArgs { day: 8, input: None, output: "forest.ppm".to_string(), format: ImageFormat::Ppm, scale: 4 })));
+        assert!(parse_args(&args("render --day 8")).is_err());
+        assert!(parse_args(&args("render --day 8 --output forest.png")).is_err());
+        assert!(parse_args(&args("render --day 8 --output forest.ppm --scale 0")).is_err());
+    }
+
+    #[test]
+    fn parses_list_command() {
+        assert_eq!(parse_args(&args("list")), Ok(Command::List));
+    }
+
+    #[test]
+    fn rejects_incomplete_or_unknown_arguments() {
+        assert!(parse_args(&args("run --part 1")).is_err());
+        assert!(parse_args(&args("run --day x --part 1")).is_err());
+        assert!(parse_args(&args("solve --day 1 --part 1")).is_err());
+        assert!(parse_args(&args("run --day 1 --part 1 --verbose")).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use std::{fs, path::{Path, PathBuf}};
+use serde::Deserialize;
+use crate::registry::Parameters;
+
+/// Expected answers for known inputs, listed as `[[answer]]` tables in a TOML file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<ExpectedAnswer>
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub part: u8,
+    /// Input file, relative to the manifest.
+    pub input: PathBuf,
+    #[serde(default)]
+    pub parameters: Parameters,
+    pub expected: String
+}
+
+pub fn parse(text: &str) -> Result<Manifest, String> {
+    return toml::from_str(text).map_err(|error| error.to_string());
+}
+
+/// Reads a manifest and resolves its input files and parameter paths against the manifest's directory.
+pub fn load(path: &Path) -> Result<Manifest, String> {
+    let text = fs::read_to_string(path).map_err(|error| format!("cannot open {}: {}", path.display(), error))?;
+    let mut manifest = parse(&text).map_err(|error| format!("{}: {}", path.display(), error))?;
+    let directory = path.parent().unwrap_or(Path::new(""));
+    for answer in manifest.answers.iter_mut() {
+        answer.input = directory.join(&answer.input);
+        answer.parameters.resolve_paths_against(directory);
+    }
+    return Ok(manifest);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{manifest, registry};
+    use std::path::Path;
+
+    #[test]
+    fn parses_answers_with_parameters() {
+        let manifest = manifest::parse("
+            [[answer]]
+            day = 11
+            part = 2
+            input = \"day11/sample.input\"
+            parameters = { rounds = 10000, relief = false }
+            expected = \"2713310158\"
+        ").unwrap();
+        assert_eq!(manifest.answers.len(), 1);
+        assert_eq!((manifest.answers[0].day, manifest.answers[0].part), (11, 2));
+        assert_eq!(manifest.answers[0].expected, "2713310158");
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(manifest::parse("[[answer]]\nday = 1\npart = 1\ninput = \"a\"\nexpected = \"1\"\nanswer = \"1\"").is_err());
+    }
+
+    #[test]
+    fn lists_valid_entries_in_the_repository_manifest() {
+        let manifest = manifest::load(Path::new("../answers.toml")).unwrap();
+        for answer in manifest.answers.iter() {
+            let entry = registry::find(answer.day).unwrap();
+            assert!((entry.configure)(&answer.parameters).is_ok(), "day {} parameters", answer.day);
+            assert!([1, 2].contains(&answer.part));
+            assert!(answer.input.is_file(), "{}", answer.input.display());
+        }
+    }
+}
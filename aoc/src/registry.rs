@@ -0,0 +1,242 @@
+use std::{fs::{self, File}, io::{BufRead, BufReader}, path::{Path, PathBuf}};
+use aoc_common::{generate::GeneratedInput, parse::ParseError, render::Render, solution::Solver};
+use day02::{day02::Day02, rules::RuleSet};
+use day03::{day03::Day03, priorities::PriorityTable};
+use day07::day07::Day07;
+use day09::day09::Day09;
+use day11::day11::Day11;
+use day15::day15::Day15;
+use serde::Deserialize;
+use toml::{Table, Value};
+
+/// Draws a picture of the puzzle state described by an input.
+pub type Picture = fn(&mut dyn BufRead) -> Result<Box<dyn Render>, ParseError>;
+
+/// A solved day together with its puzzle title, a seeded input generator using default parameters
+/// and, for grid-based days, a picture of the puzzle.
+pub struct Entry {
+    pub day: u8,
+    pub title: &'static str,
+    pub configure: fn(&Parameters) -> Result<Box<dyn Solver>, String>,
+    pub generate: fn(u64) -> GeneratedInput,
+    pub picture: Option<Picture>
+}
+
+impl Entry {
+    fn new(day: u8, title: &'static str, configure: fn(&Parameters) -> Result<Box<dyn Solver>, String>, generate: fn(u64) -> GeneratedInput) -> Entry {
+        return Entry { day, title, configure, generate, picture: None };
+    }
+
+    fn with_picture(self, picture: Picture) -> Entry {
+        return Entry { picture: Some(picture), ..self };
+    }
+
+    /// Solver configured with the actual puzzle parameters.
+    pub fn solver(&self) -> Box<dyn Solver> {
+        return (self.configure)(&Parameters::default()).expect("default parameters are always accepted");
+    }
+}
+
+/// Lists every solved day, in order.
+pub fn all() -> Vec<Entry> {
+    return vec![
+        Entry::new(1, "Calorie Counting", |p| fixed(p, day01::day01::Day01), |seed| day01::generator::generate(seed, &Default::default())),
+        Entry::new(2, "Rock Paper Scissors", configure_day02, |seed| day02::generator::generate(seed, &Default::default())),
+        Entry::new(3, "Rucksack Reorganization", configure_day03, |seed| day03::generator::generate(seed, &Default::default())),
+        Entry::new(4, "Camp Cleanup", |p| fixed(p, day04::day04::Day04), |seed| day04::generator::generate(seed, &Default::default())),
+        Entry::new(5, "Supply Stacks", |p| fixed(p, day05::day05::Day05), |seed| day05::generator::generate(seed, &Default::default())),
+        Entry::new(6, "Tuning Trouble", |p| fixed(p, day06::day06::Day06), |seed| day06::generator::generate(seed, &Default::default())),
+        Entry::new(7, "No Space Left On Device", configure_day07, |seed| day07::generator::generate(seed, &Default::default())),
+        Entry::new(8, "Treetop Tree House", |p| fixed(p, day08::day08::Day08), |seed| day08::generator::generate(seed, &Default::default())).with_picture(day08::day08::picture),
+        Entry::new(9, "Rope Bridge", configure_day09, |seed| day09::generator::generate(seed, &Default::default())).with_picture(day09::day09::picture),
+        Entry::new(10, "Cathode-Ray Tube", |p| fixed(p, day10::day10::Day10), |seed| day10::generator::generate(seed, &Default::default())),
+        Entry::new(11, "Monkey in the Middle", configure_day11, |seed| day11::generator::generate(seed, &Default::default())),
+        Entry::new(12, "Hill Climbing Algorithm", |p| fixed(p, day12::day12::Day12), |seed| day12::generator::generate(seed, &Default::default())).with_picture(day12::day12::picture),
+        Entry::new(13, "Distress Signal", |p| fixed(p, day13::day13::Day13), |seed| day13::generator::generate(seed, &Default::default())),
+        Entry::new(14, "Regolith Reservoir", |p| fixed(p, day14::day14::Day14), |seed| day14::generator::generate(seed, &Default::default())).with_picture(day14::day14::picture),
+        Entry::new(15, "Beacon Exclusion Zone", configure_day15, |seed| day15::generator::generate(seed, &Default::default())).with_picture(day15::day15::picture)
+    ];
+}
+
+pub fn find(day: u8) -> Option<Entry> {
+    return all().into_iter().find(|entry| entry.day == day);
+}
+
+fn fixed(parameters: &Parameters, solver: impl Solver + 'static) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&[])?;
+    return Ok(Box::new(solver));
+}
+
+/// `rules` names a TOML rule set to play instead of the classic moves, see [`RuleSet::from_toml`], relative to the manifest.
+fn configure_day02(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["rules"])?;
+    return match parameters.path("rules")? {
+        Some(path) => {
+            let text = fs::read_to_string(&path).map_err(|error| format!("cannot open {}: {}", path.display(), error))?;
+            Ok(Box::new(RuleSet::from_toml(&text).map_err(|error| format!("{}: {}", path.display(), error))?))
+        },
+        None => Ok(Box::new(Day02))
+    };
+}
+
+/// `group_size` sets how many consecutive rucksacks share a badge and `priorities` names a mapping file
+/// of item priorities, see [`PriorityTable::read`], relative to the manifest.
+fn configure_day03(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["group_size", "priorities"])?;
+    let group_size = parameters.integer("group_size", Day03::default().group_size)?;
+    if group_size == 0 {
+        return Err("group_size must be at least 1".to_string());
+    }
+    let priorities = match parameters.path("priorities")? {
+        Some(path) => {
+            let file = File::open(&path).map_err(|error| format!("cannot open {}: {}", path.display(), error))?;
+            PriorityTable::read(&mut BufReader::new(file)).map_err(|error| format!("{}: {}", path.display(), error))?
+        },
+        None => PriorityTable::alphabetic()
+    };
+    return Ok(Box::new(Day03 { group_size, priorities }));
+}
+
+fn configure_day07(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["total_disk_space", "free_space_needed_for_update"])?;
+    let default = Day07::default();
+    return Ok(Box::new(Day07 {
+        total_disk_space: parameters.integer("total_disk_space", default.total_disk_space)?,
+        free_space_needed_for_update: parameters.integer("free_space_needed_for_update", default.free_space_needed_for_update)?
+    }));
+}
+
+/// `knots` sets the length of the rope in both parts.
+fn configure_day09(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["knots"])?;
+    let default = Day09::default();
+    return Ok(Box::new(Day09 {
+        short_rope_knots: parameters.integer("knots", default.short_rope_knots)?,
+        long_rope_knots: parameters.integer("knots", default.long_rope_knots)?
+    }));
+}
+
+/// `rounds` and `relief` apply to both parts; `relief = false` plays without relief.
+fn configure_day11(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["rounds", "relief"])?;
+    let default = Day11::default();
+    return Ok(Box::new(Day11 {
+        part1_rounds: parameters.integer("rounds", default.part1_rounds)?,
+        part1_relief: parameters.optional_integer("relief", default.part1_relief)?,
+        part2_rounds: parameters.integer("rounds", default.part2_rounds)?,
+        part2_relief: parameters.optional_integer("relief", default.part2_relief)?
+    }));
+}
+
+fn configure_day15(parameters: &Parameters) -> Result<Box<dyn Solver>, String> {
+    parameters.allow_only(&["row", "min_coord", "max_coord"])?;
+    let default = Day15::default();
+    return Ok(Box::new(Day15 {
+        row: parameters.integer("row", default.row)?,
+        min_coord: parameters.integer("min_coord", default.min_coord)?,
+        max_coord: parameters.integer("max_coord", default.max_coord)?
+    }));
+}
+
+/// Named puzzle parameters overriding the defaults of a day, such as the rounds of day 11.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Parameters {
+    values: Table,
+    /// Directory that paths given as parameters are relative to.
+    #[serde(skip)]
+    directory: PathBuf
+}
+
+impl Parameters {
+    /// Resolves paths given as parameters against `directory` rather than the working directory.
+    pub fn resolve_paths_against(&mut self, directory: &Path) {
+        self.directory = directory.to_path_buf();
+    }
+
+    fn allow_only(&self, names: &[&str]) -> Result<(), String> {
+        return match self.values.keys().find(|name| !names.contains(&name.as_str())) {
+            Some(name) => Err(format!("unknown parameter: {}", name)),
+            None => Ok(())
+        };
+    }
+
+    fn integer<T: TryFrom<i64>>(&self, name: &str, default: T) -> Result<T, String> {
+        return match self.values.get(name) {
+            Some(value) => convert(name, value),
+            None => Ok(default)
+        };
+    }
+
+    fn string(&self, name: &str) -> Result<Option<&str>, String> {
+        return match self.values.get(name) {
+            Some(value) => value.as_str().map(Some).ok_or(format!("invalid value for parameter {}: {}", name, value)),
+            None => Ok(None)
+        };
+    }
+
+    fn path(&self, name: &str) -> Result<Option<PathBuf>, String> {
+        return Ok(self.string(name)?.map(|path| self.directory.join(path)));
+    }
+
+    /// Like `integer`, with `false` standing for `None`.
+    fn optional_integer<T: TryFrom<i64>>(&self, name: &str, default: Option<T>) -> Result<Option<T>, String> {
+        return match self.values.get(name) {
+            Some(Value::Boolean(false)) => Ok(None),
+            Some(value) => convert(name, value).map(Some),
+            None => Ok(default)
+        };
+    }
+}
+
+fn convert<T: TryFrom<i64>>(name: &str, value: &Value) -> Result<T, String> {
+    return value.as_integer()
+        .and_then(|integer| T::try_from(integer).ok())
+        .ok_or(format!("invalid value for parameter {}: {}", name, value));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use std::path::Path;
+
+    #[test]
+    fn lists_all_days_in_order() {
+        let days = registry::all().iter().map(|entry| entry.day).collect::<Vec<u8>>();
+        assert_eq!(days, (1..=15).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn finds_day_by_number() {
+        assert_eq!(registry::find(13).map(|entry| entry.title), Some("Distress Signal"));
+        assert!(registry::find(16).is_none());
+    }
+
+    #[test]
+    fn draws_pictures_of_grid_based_days() {
+        let days = registry::all().iter().filter(|entry| entry.picture.is_some()).map(|entry| entry.day).collect::<Vec<u8>>();
+        assert_eq!(days, vec![8, 9, 12, 14, 15]);
+        let picture = (registry::find(8).unwrap().picture.unwrap())(&mut "12\n34".as_bytes()).unwrap();
+        assert_eq!((picture.width(), picture.height()), (2, 2));
+    }
+
+    #[test]
+    fn configures_solvers_from_parameters() {
+        let parameters = |text: &str| toml::from_str::<registry::Parameters>(text).unwrap();
+        let configure = |day: u8, text: &str| (registry::find(day).unwrap().configure)(&parameters(text)).map(|_| ());
+        assert_eq!(configure(11, "rounds = 20\nrelief = false"), Ok(()));
+        assert_eq!(configure(15, "row = 10\nmax_coord = 20"), Ok(()));
+        assert_eq!(configure(15, "row = \"ten\""), Err("invalid value for parameter row: \"ten\"".to_string()));
+        assert_eq!(configure(2, "rules = \"../day02/rules/rpsls.toml\""), Ok(()));
+        assert!(configure(2, "rules = \"../day02/rules/missing.toml\"").is_err());
+        assert_eq!(configure(3, "group_size = 0"), Err("group_size must be at least 1".to_string()));
+        assert_eq!(configure(3, "priorities = \"../day03/fruit.priorities\""), Ok(()));
+        assert_eq!(configure(3, "priorities = 1"), Err("invalid value for parameter priorities: 1".to_string()));
+        let mut relative = parameters("priorities = \"fruit.priorities\"");
+        assert!((registry::find(3).unwrap().configure)(&relative).is_err());
+        relative.resolve_paths_against(Path::new("../day03"));
+        assert!((registry::find(3).unwrap().configure)(&relative).is_ok());
+        assert_eq!(configure(7, "total_disk_space = -1"), Err("invalid value for parameter total_disk_space: -1".to_string()));
+        assert_eq!(configure(1, "rounds = 20"), Err("unknown parameter: rounds".to_string()));
+    }
+}
@@ -0,0 +1,90 @@
+use std::{fs::File, io::{BufRead, BufReader}, time::{Duration, Instant}};
+use aoc_common::solution::Answers;
+use crate::{manifest::ExpectedAnswer, registry::{self, Parameters}};
+
+/// Solves the given part of the given day and renders the answer as text.
+pub fn run(day: u8, part: u8, input: &mut dyn BufRead) -> Result<String, String> {
+    return run_with(day, part, &Parameters::default(), input);
+}
+
+/// Like `run`, with puzzle parameters overriding the defaults of the day.
+pub fn run_with(day: u8, part: u8, parameters: &Parameters, input: &mut dyn BufRead) -> Result<String, String> {
+    let entry = registry::find(day).ok_or(format!("day {} is not solved yet", day))?;
+    return (entry.configure)(parameters)?.solve_part(input, part)
+        .ok_or(format!("day {} has no part {}", day, part))?
+        .map_err(|error| error.to_string());
+}
+
+/// Parses the input of the given day once and solves both parts.
+pub fn run_both(day: u8, input: &mut dyn BufRead) -> Result<Answers, String> {
+    let entry = registry::find(day).ok_or(format!("day {} is not solved yet", day))?;
+    return entry.solver().solve(input).map_err(|error| error.to_string());
+}
+
+/// Answer actually computed for a manifest entry, or why there is none, and how long it took.
+pub struct Verification {
+    pub actual: Result<String, String>,
+    pub elapsed: Duration
+}
+
+impl Verification {
+    pub fn passed(&self, answer: &ExpectedAnswer) -> bool {
+        return self.actual.as_ref() == Ok(&answer.expected);
+    }
+}
+
+pub fn verify(answer: &ExpectedAnswer) -> Verification {
+    let start = Instant::now();
+    let actual = File::open(&answer.input)
+        .map_err(|error| format!("cannot open {}: {}", answer.input.display(), error))
+        .and_then(|file| run_with(answer.day, answer.part, &answer.parameters, &mut BufReader::new(file)));
+    return Verification { actual, elapsed: start.elapsed() };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{manifest, runner};
+    use aoc_common::solution::Answers;
+    use std::{fs::File, io::BufReader, path::Path};
+
+    #[test]
+    fn runs_requested_day_and_part() {
+        let mut f = BufReader::new(File::open("../day12/sample.input").unwrap());
+        assert_eq!(runner::run(12, 2, &mut f), Ok("29".to_string()));
+    }
+
+    #[test]
+    fn runs_both_parts() {
+        let mut f = BufReader::new(File::open("../day01/sample.input").unwrap());
+        assert_eq!(runner::run_both(1, &mut f), Ok(Answers { part1: "24000".to_string(), part2: "45000".to_string() }));
+    }
+
+    #[test]
+    fn rejects_unknown_day_or_part() {
+        assert!(runner::run(16, 1, &mut "".as_bytes()).is_err());
+        assert!(runner::run(1, 3, &mut "".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn reports_parse_error_location() {
+        assert_eq!(runner::run(1, 1, &mut "100\n2x0".as_bytes()), Err("line 2, column 1: expected a calorie count".to_string()));
+    }
+
+    #[test]
+    fn verifies_sample_answers_of_the_repository_manifest() {
+        let manifest = manifest::load(Path::new("../answers.toml")).unwrap();
+        let samples = manifest.answers.iter().filter(|answer| answer.input.ends_with("sample.input"));
+        for answer in samples {
+            let verification = runner::verify(answer);
+            assert!(verification.passed(answer), "day {} part {}: {:?}", answer.day, answer.part, verification.actual);
+        }
+    }
+
+    #[test]
+    fn reports_missing_input_file() {
+        let manifest = manifest::parse("[[answer]]\nday = 1\npart = 1\ninput = \"missing.input\"\nexpected = \"1\"").unwrap();
+        let verification = runner::verify(&manifest.answers[0]);
+        assert!(!verification.passed(&manifest.answers[0]));
+        assert!(verification.actual.unwrap_err().starts_with("cannot open missing.input"));
+    }
+}
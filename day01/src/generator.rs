@@ -0,0 +1,52 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub elves: usize,
+    pub max_items_per_elf: i64,
+    pub max_calories: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { elves: 250, max_items_per_elf: 15, max_calories: 70000 };
+    }
+}
+
+/// Inventories of `elves` elves, each carrying at least one item.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let inventories = (0..params.elves)
+        .map(|_| {
+            let items = rng.between(1, params.max_items_per_elf);
+            return (0..items).map(|_| rng.between(1, params.max_calories)).collect();
+        })
+        .collect::<Vec<Vec<i64>>>();
+
+    let input = inventories.iter()
+        .map(|inventory| inventory.iter().map(|calories| calories.to_string()).collect::<Vec<String>>().join("\n"))
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    let mut totals = inventories.iter().map(|inventory| inventory.iter().sum()).collect::<Vec<i64>>();
+    totals.sort_unstable();
+    totals.reverse();
+
+    return GeneratedInput {
+        input,
+        part1: Some(totals.first().copied().unwrap_or(0).to_string()),
+        part2: Some(totals.iter().take(3).sum::<i64>().to_string())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day01::Day01, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day01), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,958 @@
+pub mod generator;
+
+pub mod day01 {
+    use std::{cmp::Reverse, collections::{BTreeMap, BinaryHeap}, io::BufRead};
+    use json::{object, JsonValue};
+    use aoc_common::{input::{read_input, split_sections}, parse::{number_lines, Line, ParseError}, solution::Solution};
+
+    pub fn how_many_total_calories_is_elf_carrying_at_most(input: &mut dyn BufRead) -> Result<i64, ParseError> {
+        return Ok(stream_top_totals(input, 1)?.sum());
+    }
+
+    pub fn how_many_calories_are_carrying_top_three_elves_in_total(input: &mut dyn BufRead) -> Result<i64, ParseError> {
+        return Ok(stream_top_totals(input, 3)?.sum());
+    }
+
+    /// Reads inventories line by line, keeping only the `n` highest totals seen so far.
+    ///
+    /// Accepts the same labelled inventories as the parser, ignoring the names.
+    ///
+    /// Memory use depends on `n` and the longest line, not on the size of the input.
+    pub fn stream_top_totals(input: &mut dyn BufRead, n: usize) -> Result<TopTotals, ParseError> {
+        let mut top = TopTotals::new(n);
+        let mut text = String::new();
+        let mut number = 0;
+        let mut total = 0;
+        let mut section_start = true;
+
+        loop {
+            text.clear();
+            number += 1;
+            let read = input.read_line(&mut text)
+                .map_err(|e| ParseError::new(number, 1, format!("readable UTF-8 text ({})", e)))?;
+            if read == 0 {
+                break;
+            }
+
+            let line = Line::new(number, text.strip_suffix('\n').map(|text| text.strip_suffix('\r').unwrap_or(text)).unwrap_or(&text));
+            if line.text.is_empty() {
+                top.add(total);
+                total = 0;
+                section_start = true;
+                continue;
+            }
+            if section_start && line.text.starts_with('#') {
+                parse_elf_name(&line)?;
+            }
+            else {
+                total = total.checked_add(parse_food_item(&line)?.calories)
+                    .ok_or_else(|| line.error_at(1, format!("a calorie count keeping the total within {} and {}", i64::MIN, i64::MAX)))?;
+            }
+            section_start = false;
+        }
+
+        top.add(total);
+        return Ok(top);
+    }
+
+    /// The `n` highest totals added so far, kept in a min-heap so that the lowest one is replaced first.
+    #[derive(Debug, Clone)]
+    pub struct TopTotals {
+        n: usize,
+        totals: BinaryHeap<Reverse<i64>>
+    }
+
+    impl TopTotals {
+        pub fn new(n: usize) -> TopTotals {
+            return TopTotals { n, totals: BinaryHeap::with_capacity(n + 1) };
+        }
+
+        pub fn add(&mut self, total: i64) {
+            if self.totals.len() < self.n {
+                self.totals.push(Reverse(total));
+            }
+            else if self.totals.peek().is_some_and(|Reverse(lowest)| *lowest < total) {
+                self.totals.pop();
+                self.totals.push(Reverse(total));
+            }
+        }
+
+        /// Kept totals, highest first.
+        pub fn get_totals(&self) -> Vec<i64> {
+            let mut totals = self.totals.iter().map(|Reverse(total)| *total).collect::<Vec<i64>>();
+            totals.sort_by_key(|total| Reverse(*total));
+            return totals;
+        }
+
+        pub fn sum(&self) -> i64 {
+            return self.totals.iter().map(|Reverse(total)| total).sum();
+        }
+    }
+
+    pub struct Day01;
+
+    impl Solution for Day01 {
+        type Parsed = Vec<Elf>;
+        type Answer1 = i64;
+        type Answer2 = i64;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Elf>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, elves: &Vec<Elf>) -> Result<i64, ParseError> {
+            return Ok(most_calories_carried(elves));
+        }
+
+        fn part2(&self, elves: &Vec<Elf>) -> Result<i64, ParseError> {
+            return Ok(calories_carried_by_top(elves, 3));
+        }
+    }
+
+    /// Total calories of the elf carrying the most, or 0 when there are no elves.
+    pub fn most_calories_carried<C: Carrier>(elves: &[C]) -> i64 {
+        return elves
+            .iter()
+            .map(|elf| elf.get_total_carried_calories())
+            .max().unwrap_or(0);
+    }
+
+    /// The first elf carrying the most calories.
+    pub fn elf_carrying_most<C: Carrier>(elves: &[C]) -> Option<Standing<'_>> {
+        return leaderboard(elves, 1, Order::Descending).into_iter().next();
+    }
+
+    /// Sum of the totals of the `n` elves carrying the most.
+    pub fn calories_carried_by_top<C: Carrier>(elves: &[C], n: usize) -> i64 {
+        return leaderboard(elves, n, Order::Descending)
+            .iter()
+            .map(|standing| standing.total)
+            .sum();
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Order {
+        /// Elves carrying the most calories first.
+        Descending,
+        /// Elves carrying the fewest calories first.
+        Ascending
+    }
+
+    /// An elf's place on the leaderboard.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Standing<'a> {
+        /// 1-based position of the elf's inventory in the input.
+        pub elf: usize,
+        pub name: Option<&'a str>,
+        pub total: i64,
+        pub items: &'a [i64]
+    }
+
+    impl Standing<'_> {
+        pub fn get_item_count(&self) -> usize {
+            return self.items.len();
+        }
+
+        /// The elf's name, or `Elf <position>` for unnamed elves.
+        pub fn get_label(&self) -> String {
+            return self.name.map(String::from).unwrap_or_else(|| format!("Elf {}", self.elf));
+        }
+    }
+
+    /// The first `n` elves by total calories in the given order; elves with equal totals keep their input order.
+    pub fn leaderboard<C: Carrier>(elves: &[C], n: usize, order: Order) -> Vec<Standing<'_>> {
+        let mut standings = elves
+            .iter()
+            .enumerate()
+            .map(|(i, elf)| Standing { elf: i + 1, name: elf.get_name(), total: elf.get_total_carried_calories(), items: elf.get_carried_food_calories() })
+            .collect::<Vec<Standing>>();
+        match order {
+            Order::Descending => standings.sort_by_key(|standing| Reverse(standing.total)),
+            Order::Ascending => standings.sort_by_key(|standing| standing.total)
+        }
+        standings.truncate(n);
+        return standings;
+    }
+
+    /// Percentiles included in the CSV and JSON reports.
+    pub const REPORTED_PERCENTILES: [u8; 5] = [10, 25, 75, 90, 99];
+
+    /// Summary of the totals and items of all elves.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct InventoryStatistics {
+        sorted_totals: Vec<i64>,
+        /// Number of elves by the number of items they carry.
+        pub item_counts: BTreeMap<usize, usize>,
+        pub heaviest_item: Option<HeaviestItem>,
+        pub histogram: Vec<Bucket>
+    }
+
+    /// The item with the most calories and the 1-based position of the first elf carrying it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct HeaviestItem {
+        pub elf: usize,
+        pub calories: i64
+    }
+
+    /// Number of elves whose totals are at least `from` and below `to`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Bucket {
+        pub from: i64,
+        pub to: i64,
+        pub elves: usize
+    }
+
+    /// Statistics of the elves' totals, with a histogram of `bucket_width` calories wide buckets.
+    ///
+    /// Buckets start at a multiple of `bucket_width` and cover every total from the lowest to the highest one,
+    /// including buckets without any elf. Bucket bounds beyond the range of `i64` are clamped to it.
+    pub fn statistics<C: Carrier>(elves: &[C], bucket_width: i64) -> Result<InventoryStatistics, String> {
+        if bucket_width <= 0 {
+            return Err(format!("bucket width must be positive, not {}", bucket_width));
+        }
+        let mut sorted_totals = elves.iter().map(|elf| elf.get_total_carried_calories()).collect::<Vec<i64>>();
+        sorted_totals.sort();
+
+        let mut item_counts = BTreeMap::new();
+        for elf in elves.iter() {
+            *item_counts.entry(elf.get_carried_food_calories().len()).or_insert(0) += 1;
+        }
+
+        let mut heaviest_item: Option<HeaviestItem> = None;
+        for (i, elf) in elves.iter().enumerate() {
+            for calories in elf.get_carried_food_calories().iter() {
+                if heaviest_item.is_none_or(|heaviest| heaviest.calories < *calories) {
+                    heaviest_item = Some(HeaviestItem { elf: i + 1, calories: *calories });
+                }
+            }
+        }
+
+        let mut histogram = Vec::new();
+        if let (Some(lowest), Some(highest)) = (sorted_totals.first(), sorted_totals.last()) {
+            // Bounds are computed in 128 bits, as those of the outermost buckets may not fit in 64.
+            let width = i128::from(bucket_width);
+            let start = i128::from(*lowest).div_euclid(width) * width;
+            let bucket_of = |total: i64| ((i128::from(total) - start) / width) as usize;
+            let bound = |bucket: usize| (start + bucket as i128 * width).clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+
+            let mut counts = vec![0; bucket_of(*highest) + 1];
+            for total in sorted_totals.iter() {
+                counts[bucket_of(*total)] += 1;
+            }
+            histogram = counts.into_iter().enumerate()
+                .map(|(bucket, elves)| Bucket { from: bound(bucket), to: bound(bucket + 1), elves })
+                .collect();
+        }
+
+        return Ok(InventoryStatistics { sorted_totals, item_counts, heaviest_item, histogram });
+    }
+
+    impl InventoryStatistics {
+        pub fn get_elf_count(&self) -> usize {
+            return self.sorted_totals.len();
+        }
+
+        pub fn mean(&self) -> Option<f64> {
+            if self.sorted_totals.is_empty() {
+                return None;
+            }
+            // Summed in 128 bits, as the totals of a few heavy elves can already exceed `i64`.
+            return Some(self.sorted_totals.iter().map(|total| i128::from(*total)).sum::<i128>() as f64 / self.sorted_totals.len() as f64);
+        }
+
+        /// Middle total, or the mean of the two middle ones for an even number of elves.
+        pub fn median(&self) -> Option<f64> {
+            let n = self.sorted_totals.len();
+            if n == 0 {
+                return None;
+            }
+            if n % 2 == 1 {
+                return Some(self.sorted_totals[n / 2] as f64);
+            }
+            let (lower, upper) = (self.sorted_totals[n / 2 - 1], self.sorted_totals[n / 2]);
+            return Some((i128::from(lower) + i128::from(upper)) as f64 / 2.0);
+        }
+
+        /// Nearest-rank percentile: the lowest total that at least `p` percent of the elves do not exceed.
+        pub fn percentile(&self, p: u8) -> Option<i64> {
+            let rank = (p.min(100) as usize * self.sorted_totals.len()).div_ceil(100);
+            return self.sorted_totals.get(rank.max(1) - 1).copied();
+        }
+
+        /// Rows of `section,key,value`, one per reported figure.
+        pub fn to_csv(&self) -> String {
+            let optional = |value: Option<String>| value.unwrap_or_default();
+            let mut rows = vec![
+                "section,key,value".to_string(),
+                format!("summary,elves,{}", self.get_elf_count()),
+                format!("summary,mean,{}", optional(self.mean().map(|mean| mean.to_string()))),
+                format!("summary,median,{}", optional(self.median().map(|median| median.to_string()))),
+                format!("summary,heaviest_item_calories,{}", optional(self.heaviest_item.map(|item| item.calories.to_string()))),
+                format!("summary,heaviest_item_elf,{}", optional(self.heaviest_item.map(|item| item.elf.to_string())))
+            ];
+            rows.extend(REPORTED_PERCENTILES.iter()
+                .map(|p| format!("percentile,p{},{}", p, optional(self.percentile(*p).map(|total| total.to_string())))));
+            rows.extend(self.item_counts.iter().map(|(items, elves)| format!("item_count,{},{}", items, elves)));
+            rows.extend(self.histogram.iter().map(|bucket| format!("histogram,{}..{},{}", bucket.from, bucket.to, bucket.elves)));
+            return rows.join("\n") + "\n";
+        }
+
+        pub fn to_json(&self) -> JsonValue {
+            let mut percentiles = JsonValue::new_object();
+            for p in REPORTED_PERCENTILES.iter() {
+                percentiles[format!("p{}", p)] = self.percentile(*p).into();
+            }
+            let mut item_counts = JsonValue::new_object();
+            for (items, elves) in self.item_counts.iter() {
+                item_counts[items.to_string()] = (*elves).into();
+            }
+            return object! {
+                elves: self.get_elf_count(),
+                mean: self.mean(),
+                median: self.median(),
+                percentiles: percentiles,
+                item_counts: item_counts,
+                heaviest_item: self.heaviest_item.map(|item| object! { elf: item.elf, calories: item.calories }),
+                histogram: self.histogram.iter()
+                    .map(|bucket| object! { from: bucket.from, to: bucket.to, elves: bucket.elves })
+                    .collect::<Vec<JsonValue>>()
+            };
+        }
+    }
+
+    /// Largest number of ways to hand out all items that is still searched exhaustively.
+    const EXACT_SEARCH_LIMIT: u64 = 10_000_000;
+
+    /// A food item handed from one elf to another; elves are numbered from 1 in input order.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct Transfer {
+        pub calories: i64,
+        pub from: usize,
+        pub to: usize
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RebalancingPlan {
+        pub moves: Vec<Transfer>,
+        /// Totals of every elf once the moves are done.
+        pub totals: Vec<i64>,
+        /// Whether no other plan has a smaller spread, or the same spread with fewer moves.
+        pub exact: bool
+    }
+
+    impl RebalancingPlan {
+        /// Difference between the highest and the lowest total.
+        pub fn spread(&self) -> i64 {
+            return self.totals.iter().max().unwrap_or(&0) - self.totals.iter().min().unwrap_or(&0);
+        }
+    }
+
+    /// Moves food items between elves so that their totals end up as close to each other as possible.
+    ///
+    /// Small inventories are searched exhaustively, larger ones are improved with [`plan_heuristic_rebalancing`].
+    pub fn plan_rebalancing<C: Carrier>(elves: &[C]) -> RebalancingPlan {
+        let items = elves.iter().map(|elf| elf.get_carried_food_calories().len() as u32).sum();
+        return match (elves.len() as u64).checked_pow(items) {
+            Some(assignments) if assignments <= EXACT_SEARCH_LIMIT => plan_exact_rebalancing(elves),
+            _ => plan_heuristic_rebalancing(elves)
+        };
+    }
+
+    /// Branch and bound search over every way to hand out the items, minimising the spread and then the number of moves.
+    ///
+    /// Items are placed from the largest one, first with their current owner and then with the lightest elves,
+    /// and partial plans are cut off once they cannot beat the best plan found so far.
+    pub fn plan_exact_rebalancing<C: Carrier>(elves: &[C]) -> RebalancingPlan {
+        let mut items = owned_items(elves);
+        items.sort_by_key(|(calories, _)| Reverse(*calories));
+
+        let total: i64 = items.iter().map(|(calories, _)| calories).sum();
+        let mut search = ExactSearch {
+            lowest_max: total.div_euclid(elves.len().max(1) as i64) + (total.rem_euclid(elves.len().max(1) as i64) > 0) as i64,
+            highest_min: total.div_euclid(elves.len().max(1) as i64),
+            positive_suffix: suffix_sums(&items, |calories| calories.max(0)),
+            negative_suffix: suffix_sums(&items, |calories| calories.min(0)),
+            loads: vec![0; elves.len()],
+            last_owned: (0..elves.len()).map(|elf| items.iter().rposition(|(_, owner)| *owner == elf)).collect(),
+            assignment: vec![0; items.len()],
+            best: None,
+            items
+        };
+        search.place(0, 0);
+
+        let assignment = search.best.map(|(_, _, assignment)| assignment).unwrap_or_default();
+        return plan_from_assignment(elves.len(), &search.items, &assignment, true);
+    }
+
+    /// Local search from the current inventories: while the heaviest and the lightest elf can get closer by one of
+    /// them giving the other an item, or by swapping two items, the exchange leaving them closest is made.
+    ///
+    /// Only the items of those exchanges move, so an inventory that is nearly balanced keeps most of its items in
+    /// place. The result may not have the smallest possible spread, but it is never wider than the current one.
+    pub fn plan_heuristic_rebalancing<C: Carrier>(elves: &[C]) -> RebalancingPlan {
+        let items = owned_items(elves);
+        let mut inventories = vec![Vec::new(); elves.len()];
+        for (i, (_, owner)) in items.iter().enumerate() {
+            inventories[*owner].push(i);
+        }
+
+        // Every exchange brings two elves strictly closer without passing each other, so the sum of the squared
+        // totals decreases and the search ends.
+        let mut loads = inventories.iter().map(|inventory| inventory.iter().map(|i| items[*i].0).sum()).collect::<Vec<i64>>();
+        while let (Some(heaviest), Some(lightest)) = (index_of_extreme(&loads, true), index_of_extreme(&loads, false)) {
+            let (shift, give, take) = match best_exchange(&items, &inventories[heaviest], &inventories[lightest], loads[heaviest] - loads[lightest]) {
+                Some(exchange) => exchange,
+                None => break
+            };
+            if let Some(i) = give {
+                inventories[heaviest].retain(|item| *item != i);
+                inventories[lightest].push(i);
+            }
+            if let Some(j) = take {
+                inventories[lightest].retain(|item| *item != j);
+                inventories[heaviest].push(j);
+            }
+            loads[heaviest] -= shift;
+            loads[lightest] += shift;
+        }
+
+        let mut assignment = vec![0; items.len()];
+        for (elf, inventory) in inventories.iter().enumerate() {
+            for i in inventory.iter() {
+                assignment[*i] = elf;
+            }
+        }
+        return plan_from_assignment(elves.len(), &items, &assignment, false);
+    }
+
+    /// The exchange between a heavier and a lighter elf `gap` calories apart that leaves them closest, as the
+    /// calories shifted to the lighter elf together with the item it is given and the one taken from it.
+    fn best_exchange(items: &[(i64, usize)], heavy_items: &[usize], light_items: &[usize], gap: i64) -> Option<(i64, Option<usize>, Option<usize>)> {
+        let given = heavy_items.iter().map(|i| (items[*i].0, Some(*i), None));
+        let taken = light_items.iter().map(|j| (-items[*j].0, None, Some(*j)));
+        let swapped = heavy_items.iter()
+            .flat_map(|i| light_items.iter().map(|j| (items[*i].0 - items[*j].0, Some(*i), Some(*j))));
+
+        return given.chain(taken).chain(swapped)
+            .filter(|(shift, _, _)| 0 < *shift && *shift < gap)
+            .min_by_key(|(shift, _, _)| (2 * shift - gap).abs());
+    }
+
+    struct ExactSearch {
+        /// Items as `(calories, owner)`, largest first.
+        items: Vec<(i64, usize)>,
+        positive_suffix: Vec<i64>,
+        negative_suffix: Vec<i64>,
+        /// No plan can have a highest total below this one, nor a lowest total above `highest_min`.
+        lowest_max: i64,
+        highest_min: i64,
+        loads: Vec<i64>,
+        /// Index of the last item of each elf, if it owns any.
+        last_owned: Vec<Option<usize>>,
+        assignment: Vec<usize>,
+        best: Option<(i64, usize, Vec<usize>)>
+    }
+
+    impl ExactSearch {
+        fn place(&mut self, item: usize, moves: usize) {
+            let highest = *self.loads.iter().max().unwrap_or(&0);
+            let lowest = *self.loads.iter().min().unwrap_or(&0);
+
+            if item == self.items.len() {
+                if self.best.as_ref().is_none_or(|(spread, best_moves, _)| (highest - lowest, moves) < (*spread, *best_moves)) {
+                    self.best = Some((highest - lowest, moves, self.assignment.clone()));
+                }
+                return;
+            }
+
+            let bound = (highest + self.negative_suffix[item]).max(self.lowest_max)
+                - (lowest + self.positive_suffix[item]).min(self.highest_min);
+            if self.best.as_ref().is_some_and(|(spread, best_moves, _)| (bound.max(0), moves) >= (*spread, *best_moves)) {
+                return;
+            }
+
+            // Elves owning none of the items left only differ by their load, so one of them per load is enough.
+            let (calories, owner) = self.items[item];
+            let owns_items_left = |elf: usize| self.last_owned[elf].is_some_and(|last| last > item);
+            let mut others = (0..self.loads.len()).filter(|elf| *elf != owner).collect::<Vec<usize>>();
+            others.sort_by_key(|elf| (self.loads[*elf], owns_items_left(*elf)));
+            others.dedup_by(|elf, previous| !owns_items_left(*elf) && !owns_items_left(*previous) && self.loads[*elf] == self.loads[*previous]);
+            for elf in std::iter::once(owner).chain(others) {
+                self.loads[elf] += calories;
+                self.assignment[item] = elf;
+                self.place(item + 1, moves + (elf != owner) as usize);
+                self.loads[elf] -= calories;
+            }
+        }
+    }
+
+    /// Every item as `(calories, owner)`, owners numbered from 0.
+    fn owned_items<C: Carrier>(elves: &[C]) -> Vec<(i64, usize)> {
+        return elves.iter()
+            .enumerate()
+            .flat_map(|(owner, elf)| elf.get_carried_food_calories().iter().map(move |calories| (*calories, owner)))
+            .collect();
+    }
+
+    /// Sums of `part` of the items from each position to the end.
+    fn suffix_sums(items: &[(i64, usize)], part: fn(i64) -> i64) -> Vec<i64> {
+        let mut sums = vec![0; items.len() + 1];
+        for i in (0..items.len()).rev() {
+            sums[i] = sums[i + 1] + part(items[i].0);
+        }
+        return sums;
+    }
+
+    /// Index of the first highest or lowest load.
+    fn index_of_extreme(loads: &[i64], highest: bool) -> Option<usize> {
+        return (0..loads.len()).reduce(|best, i| if (highest && loads[i] > loads[best]) || (!highest && loads[i] < loads[best]) { i } else { best });
+    }
+
+    fn plan_from_assignment(elves: usize, items: &[(i64, usize)], assignment: &[usize], exact: bool) -> RebalancingPlan {
+        let mut totals = vec![0; elves];
+        let mut moves = Vec::new();
+        for ((calories, owner), elf) in items.iter().zip(assignment.iter()) {
+            totals[*elf] += calories;
+            if owner != elf {
+                moves.push(Transfer { calories: *calories, from: owner + 1, to: elf + 1 });
+            }
+        }
+        moves.sort_by_key(|transfer| (transfer.from, Reverse(transfer.calories), transfer.to));
+        return RebalancingPlan { moves, totals, exact };
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Elf>, ParseError> {
+        let lines = read_input(input)?;
+        return split_sections(&number_lines(&lines))
+            .iter()
+            .map(|inventory| parse_inventory(inventory))
+            .collect();
+    }
+
+    /// An inventory, optionally starting with a `# Elf Name` header.
+    fn parse_inventory(lines: &[Line]) -> Result<Elf, ParseError> {
+        let (name, items) = match lines.split_first() {
+            Some((header, items)) if header.text.starts_with('#') => (Some(parse_elf_name(header)?), items),
+            _ => (None, lines)
+        };
+        let items = items
+            .iter()
+            .map(parse_food_item)
+            .collect::<Result<Vec<FoodItem>, ParseError>>()?;
+        return Ok(Elf::labelled(name, items));
+    }
+
+    fn parse_elf_name(line: &Line) -> Result<String, ParseError> {
+        let name = line.text[1..].trim();
+        if name.is_empty() {
+            return Err(line.error_at(2, "an elf name"));
+        }
+        return Ok(name.to_string());
+    }
+
+    /// Calories optionally followed by the name of the food, as in `1200 apple`.
+    fn parse_food_item(line: &Line) -> Result<FoodItem, ParseError> {
+        let (calories, name) = match line.text.split_once(char::is_whitespace) {
+            Some((calories, name)) => (calories, Some(name.trim()).filter(|name| !name.is_empty())),
+            None => (line.text, None)
+        };
+        return Ok(FoodItem {
+            calories: line.parse::<i64>(calories, "a calorie count")?,
+            name: name.map(String::from)
+        });
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct FoodItem {
+        pub calories: i64,
+        pub name: Option<String>
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Elf {
+        name: Option<String>,
+        carried_food_calories: Vec<i64>,
+        food_names: Vec<Option<String>>
+    }
+
+    impl Elf {
+        pub fn new(food_calories: Vec<i64>) -> Elf {
+            Elf {
+                name: None,
+                food_names: vec![None; food_calories.len()],
+                carried_food_calories: food_calories
+            }
+        }
+
+        pub fn labelled(name: Option<String>, items: Vec<FoodItem>) -> Elf {
+            return Elf {
+                name,
+                carried_food_calories: items.iter().map(|item| item.calories).collect(),
+                food_names: items.into_iter().map(|item| item.name).collect()
+            };
+        }
+
+        pub fn get_name(&self) -> Option<&str> {
+            return self.name.as_deref();
+        }
+
+        pub fn get_carried_food_calories(&self) -> &[i64] {
+            return &self.carried_food_calories;
+        }
+
+        pub fn get_food_items(&self) -> Vec<FoodItem> {
+            return self.carried_food_calories.iter()
+                .zip(self.food_names.iter())
+                .map(|(calories, name)| FoodItem { calories: *calories, name: name.clone() })
+                .collect();
+        }
+    }
+
+    /// Totals of one kind of food across all elves; `name` is `None` for the items without a name.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct FoodAggregate {
+        pub name: Option<String>,
+        pub items: usize,
+        pub calories: i64,
+        /// Number of elves carrying at least one such item.
+        pub carriers: usize
+    }
+
+    /// Aggregates of every kind of food, the unnamed items first and then by name.
+    pub fn food_aggregates(elves: &[Elf]) -> Vec<FoodAggregate> {
+        let mut aggregates: BTreeMap<Option<&str>, FoodAggregate> = BTreeMap::new();
+        for elf in elves.iter() {
+            let mut carried: Vec<Option<&str>> = Vec::new();
+            for (calories, name) in elf.carried_food_calories.iter().zip(elf.food_names.iter()) {
+                let name = name.as_deref();
+                let aggregate = aggregates.entry(name)
+                    .or_insert_with(|| FoodAggregate { name: name.map(String::from), items: 0, calories: 0, carriers: 0 });
+                aggregate.items += 1;
+                aggregate.calories += calories;
+                if !carried.contains(&name) {
+                    aggregate.carriers += 1;
+                    carried.push(name);
+                }
+            }
+        }
+        return aggregates.into_values().collect();
+    }
+
+    pub trait Carrier {
+        fn get_carried_food_calories(&self) -> &[i64];
+
+        fn get_name(&self) -> Option<&str> {
+            return None;
+        }
+
+        fn get_total_carried_calories(&self) -> i64 {
+            return self.get_carried_food_calories().iter().sum();
+        }
+    }
+
+    impl Carrier for Elf {
+        fn get_carried_food_calories(&self) -> &[i64] {
+            return &self.carried_food_calories;
+        }
+
+        fn get_name(&self) -> Option<&str> {
+            return self.name.as_deref();
+        }
+
+        fn get_total_carried_calories(&self) -> i64 {
+            return self.carried_food_calories.iter().sum();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day01::{self, Carrier}, generator};
+    use aoc_common::{generate::Rng, solution::Solution};
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn day01_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day01::how_many_total_calories_is_elf_carrying_at_most(&mut f).unwrap(), 24000);
+    }
+
+    #[test]
+    fn day01_part1_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day01::how_many_total_calories_is_elf_carrying_at_most(&mut f).unwrap(), 69836);
+    }
+
+    #[test]
+    fn day01_part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day01::how_many_calories_are_carrying_top_three_elves_in_total(&mut f).unwrap(), 45000);
+    }
+
+    #[test]
+    fn day01_part2_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day01::how_many_calories_are_carrying_top_three_elves_in_total(&mut f).unwrap(), 207968);
+    }
+
+    #[test]
+    fn reports_malformed_calorie_count() {
+        let error = day01::how_many_total_calories_is_elf_carrying_at_most(&mut "1000\n\n20x0".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(3, 1, "a calorie count"));
+    }
+
+    #[test]
+    fn queries_elves_through_library_api() {
+        let elves = vec![day01::Elf::new(vec![1000, 2000]), day01::Elf::new(vec![4000]), day01::Elf::new(vec![500])];
+        assert_eq!(elves[0].get_carried_food_calories(), &[1000, 2000]);
+        assert_eq!(day01::most_calories_carried(&elves), 4000);
+        assert_eq!(day01::calories_carried_by_top(&elves, 2), 7000);
+    }
+
+    #[test]
+    fn ranks_elves_on_leaderboard() {
+        let elves = vec![day01::Elf::new(vec![1000, 2000]), day01::Elf::new(vec![4000]), day01::Elf::new(vec![500]), day01::Elf::new(vec![3000])];
+        let top = day01::leaderboard(&elves, 3, day01::Order::Descending);
+        assert_eq!(top.iter().map(|standing| (standing.elf, standing.total)).collect::<Vec<(usize, i64)>>(), vec![(2, 4000), (1, 3000), (4, 3000)]);
+        assert_eq!(top[1].items, &[1000, 2000]);
+        assert_eq!(top[1].get_item_count(), 2);
+
+        let bottom = day01::leaderboard(&elves, 2, day01::Order::Ascending);
+        assert_eq!(bottom.iter().map(|standing| standing.elf).collect::<Vec<usize>>(), vec![3, 1]);
+        assert_eq!(day01::leaderboard(&elves, 10, day01::Order::Descending).len(), 4);
+    }
+
+    #[test]
+    fn streams_same_totals_as_parsed_inventories() {
+        let inputs = (0..10)
+            .map(|seed| generator::generate(seed, &generator::Params { elves: 50, ..Default::default() }).input)
+            .chain(["", "\n", "100", "100\n\n\n200\n", "-5\r\n\r\n-7"].map(String::from))
+            .collect::<Vec<String>>();
+        for input in inputs.iter() {
+            let elves = day01::Day01.parse(&mut input.as_bytes()).unwrap();
+            for n in 0..5 {
+                let top = day01::stream_top_totals(&mut input.as_bytes(), n).unwrap();
+                assert_eq!(top.sum(), day01::calories_carried_by_top(&elves, n), "top {} of {:?}", n, input);
+                assert_eq!(top.get_totals(), day01::leaderboard(&elves, n, day01::Order::Descending).iter().map(|standing| standing.total).collect::<Vec<i64>>());
+            }
+        }
+    }
+
+    #[test]
+    fn reports_malformed_calorie_count_while_streaming() {
+        let error = day01::stream_top_totals(&mut "1000\n\n20x0".as_bytes(), 3).unwrap_err();
+        assert_eq!(error, ParseError::new(3, 1, "a calorie count"));
+        let error = day01::stream_top_totals(&mut &b"1\n\xff\n"[..], 3).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let input = format!("1\n\n{}\n1\n\n2", i64::MAX);
+        let error = day01::stream_top_totals(&mut input.as_bytes(), 3).unwrap_err();
+        assert_eq!(error, ParseError::new(4, 1, format!("a calorie count keeping the total within {} and {}", i64::MIN, i64::MAX)));
+    }
+
+    #[test]
+    fn summarizes_inventories() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let elves = day01::Day01.parse(&mut f).unwrap();
+        let statistics = day01::statistics(&elves, 10000).unwrap();
+        assert_eq!(statistics.get_elf_count(), 5);
+        assert_eq!(statistics.mean(), Some(11000.0));
+        assert_eq!(statistics.median(), Some(10000.0));
+        assert_eq!((statistics.percentile(0), statistics.percentile(50), statistics.percentile(90)), (Some(4000), Some(10000), Some(24000)));
+        assert_eq!(statistics.item_counts.iter().map(|(items, elves)| (*items, *elves)).collect::<Vec<(usize, usize)>>(), vec![(1, 2), (2, 1), (3, 2)]);
+        assert_eq!(statistics.heaviest_item, Some(day01::HeaviestItem { elf: 5, calories: 10000 }));
+        assert_eq!(statistics.histogram, vec![
+            day01::Bucket { from: 0, to: 10000, elves: 2 },
+            day01::Bucket { from: 10000, to: 20000, elves: 2 },
+            day01::Bucket { from: 20000, to: 30000, elves: 1 }
+        ]);
+    }
+
+    #[test]
+    fn buckets_extreme_totals() {
+        let elves = vec![day01::Elf::new(vec![95]), day01::Elf::new(vec![i64::MAX - 1])];
+        let statistics = day01::statistics(&elves, i64::MAX / 2).unwrap();
+        assert_eq!(statistics.histogram, vec![
+            day01::Bucket { from: 0, to: i64::MAX / 2, elves: 1 },
+            day01::Bucket { from: i64::MAX / 2, to: i64::MAX - 1, elves: 0 },
+            day01::Bucket { from: i64::MAX - 1, to: i64::MAX, elves: 1 }
+        ]);
+        let half = (i64::MAX / 2) as f64;
+        assert!((statistics.mean().unwrap() - half).abs() < 1e3);
+        assert!((statistics.median().unwrap() - half).abs() < 1e3);
+        assert!(statistics.to_csv().contains("\nsummary,elves,2\n"));
+        assert_eq!(statistics.to_json()["percentiles"]["p99"], i64::MAX - 1);
+
+        let elves = vec![day01::Elf::new(vec![-15]), day01::Elf::new(vec![4]), day01::Elf::new(vec![-9])];
+        let buckets = day01::statistics(&elves, 10).unwrap().histogram.iter().map(|bucket| (bucket.from, bucket.elves)).collect::<Vec<(i64, usize)>>();
+        assert_eq!(buckets, vec![(-20, 1), (-10, 1), (0, 1)]);
+
+        assert_eq!(day01::statistics(&elves, 0), Err("bucket width must be positive, not 0".to_string()));
+    }
+
+    #[test]
+    fn exports_statistics() {
+        let elves = vec![day01::Elf::new(vec![1000, 2000]), day01::Elf::new(vec![4000])];
+        let statistics = day01::statistics(&elves, 2500).unwrap();
+        let csv = statistics.to_csv();
+        assert!(csv.starts_with("section,key,value\nsummary,elves,2\nsummary,mean,3500\nsummary,median,3500\n"));
+        assert!(csv.contains("\nsummary,heaviest_item_elf,2\npercentile,p10,3000\n"));
+        assert!(csv.ends_with("\nitem_count,1,1\nitem_count,2,1\nhistogram,2500..5000,2\n"));
+
+        let json = statistics.to_json();
+        assert_eq!(json["percentiles"]["p99"], 4000);
+        assert_eq!(json["heaviest_item"]["calories"], 4000);
+        assert_eq!(json["histogram"][0]["elves"], 2);
+
+        let empty = day01::statistics::<day01::Elf>(&[], 100).unwrap();
+        assert_eq!(empty.to_json().dump(), "{\"elves\":0,\"mean\":null,\"median\":null,\"percentiles\":{\"p10\":null,\"p25\":null,\"p75\":null,\"p90\":null,\"p99\":null},\"item_counts\":{},\"heaviest_item\":null,\"histogram\":[]}");
+        assert!(empty.to_csv().contains("summary,mean,\n"));
+    }
+
+    #[test]
+    fn plans_exact_rebalancing_of_sample() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let plan = day01::plan_rebalancing(&day01::Day01.parse(&mut f).unwrap());
+        assert!(plan.exact);
+        assert_eq!(plan.totals, vec![11000; 5]);
+        assert_eq!(plan.spread(), 0);
+        assert_eq!(plan.moves.len(), 4);
+    }
+
+    fn spread_of(elves: &[day01::Elf]) -> i64 {
+        let totals = elves.iter().map(|elf| elf.get_total_carried_calories()).collect::<Vec<i64>>();
+        return totals.iter().max().unwrap() - totals.iter().min().unwrap();
+    }
+
+    /// Inventories of 2 to 4 elves with at most `max_items` items in total.
+    fn random_inventories(count: usize, max_items: i64) -> Vec<Vec<day01::Elf>> {
+        let mut rng = Rng::new(1);
+        return (0..count)
+            .map(|_| {
+                let mut elves = (0..rng.between(2, 4)).map(|_| Vec::new()).collect::<Vec<Vec<i64>>>();
+                for _ in 0..rng.between(1, max_items) {
+                    let elf = rng.index(elves.len());
+                    elves[elf].push(rng.between(1, 10));
+                }
+                return elves.into_iter().map(day01::Elf::new).collect();
+            })
+            .collect();
+    }
+
+    /// Smallest `(spread, moves)` over every way to hand out the items.
+    fn brute_force_rebalancing(elves: &[day01::Elf]) -> (i64, usize) {
+        let items = elves.iter().enumerate()
+            .flat_map(|(owner, elf)| elf.get_carried_food_calories().iter().map(move |calories| (*calories, owner)))
+            .collect::<Vec<(i64, usize)>>();
+        let mut best = (i64::MAX, usize::MAX);
+        for code in 0..elves.len().pow(items.len() as u32) {
+            let (mut totals, mut moves, mut rest) = (vec![0; elves.len()], 0, code);
+            for (calories, owner) in items.iter() {
+                let elf = rest % elves.len();
+                rest /= elves.len();
+                totals[elf] += calories;
+                moves += (elf != *owner) as usize;
+            }
+            best = best.min((totals.iter().max().unwrap() - totals.iter().min().unwrap(), moves));
+        }
+        return best;
+    }
+
+    #[test]
+    fn exact_rebalancing_matches_brute_force() {
+        let elves = vec![day01::Elf::new(vec![8, 4]), day01::Elf::new(vec![6, 7]), day01::Elf::new(vec![9, 3]), day01::Elf::new(vec![1])];
+        let plan = day01::plan_exact_rebalancing(&elves);
+        assert_eq!((plan.spread(), plan.moves.len()), (1, 3));
+
+        for elves in random_inventories(100, 8) {
+            let plan = day01::plan_exact_rebalancing(&elves);
+            assert_eq!((plan.spread(), plan.moves.len()), brute_force_rebalancing(&elves), "{:?}", elves);
+        }
+    }
+
+    #[test]
+    fn prefers_fewer_moves_among_balanced_plans() {
+        let elves = vec![day01::Elf::new(vec![5, 1]), day01::Elf::new(vec![3, 3]), day01::Elf::new(vec![])];
+        let plan = day01::plan_exact_rebalancing(&elves);
+        assert_eq!((plan.spread(), plan.moves.len()), (2, 2));
+        assert_eq!(plan.moves[0], day01::Transfer { calories: 1, from: 1, to: 2 });
+
+        let balanced = vec![day01::Elf::new(vec![2, 3]), day01::Elf::new(vec![5])];
+        assert_eq!(day01::plan_exact_rebalancing(&balanced).moves, vec![]);
+    }
+
+    #[test]
+    fn heuristic_rebalancing_narrows_spread() {
+        for seed in 0..5 {
+            let input = generator::generate(seed, &generator::Params { elves: 30, ..Default::default() }).input;
+            let elves = day01::Day01.parse(&mut input.as_bytes()).unwrap();
+            let plan = day01::plan_rebalancing(&elves);
+            assert!(!plan.exact);
+            assert!(plan.spread() < spread_of(&elves));
+            assert_eq!(plan.totals.iter().sum::<i64>(), elves.iter().flat_map(|elf| elf.get_carried_food_calories()).sum::<i64>());
+        }
+
+        for elves in random_inventories(50, 12) {
+            let heuristic = day01::plan_heuristic_rebalancing(&elves);
+            let exact = day01::plan_exact_rebalancing(&elves);
+            assert!(exact.spread() <= heuristic.spread() && heuristic.spread() <= spread_of(&elves), "{:?}", elves);
+        }
+
+        let elves = vec![day01::Elf::new(vec![9, 2, 1]), day01::Elf::new(vec![4])];
+        let plan = day01::plan_heuristic_rebalancing(&elves);
+        assert_eq!(plan.totals, vec![7, 9]);
+        assert_eq!(plan.moves, vec![day01::Transfer { calories: 9, from: 1, to: 2 }, day01::Transfer { calories: 4, from: 2, to: 1 }]);
+    }
+
+    #[test]
+    fn heuristic_rebalancing_moves_few_items_of_nearly_balanced_inventories() {
+        let mut elves = (0..200).map(|elf| day01::Elf::new(vec![5 + elf % 3, 5, 10 - elf % 3])).collect::<Vec<day01::Elf>>();
+        elves.push(day01::Elf::new(vec![5, 5, 10, 2]));
+        elves.push(day01::Elf::new(vec![5, 5, 8]));
+        let plan = day01::plan_heuristic_rebalancing(&elves);
+        assert_eq!(plan.spread(), 0);
+        assert_eq!(plan.moves, vec![day01::Transfer { calories: 2, from: 201, to: 202 }]);
+    }
+
+    #[test]
+    fn reads_labelled_inventories() {
+        let input = "# Alice Smith\n1000 apple\n2000 trail mix\n\n3000\n\n#Bob\n4000 apple\n500 apple\n\n";
+        let elves = day01::Day01.parse(&mut input.as_bytes()).unwrap();
+        assert_eq!(elves.len(), 4);
+        assert_eq!(elves[0].get_name(), Some("Alice Smith"));
+        assert_eq!(elves[0].get_food_items(), vec![
+            day01::FoodItem { calories: 1000, name: Some("apple".to_string()) },
+            day01::FoodItem { calories: 2000, name: Some("trail mix".to_string()) }
+        ]);
+        assert_eq!(elves[1], day01::Elf::new(vec![3000]));
+        assert_eq!(elves[3], day01::Elf::new(vec![]));
+
+        let most = day01::elf_carrying_most(&elves).unwrap();
+        assert_eq!((most.get_label(), most.total), ("Bob".to_string(), 4500));
+        assert_eq!(day01::leaderboard(&elves, 3, day01::Order::Descending)[2].get_label(), "Elf 2");
+        assert_eq!(day01::how_many_calories_are_carrying_top_three_elves_in_total(&mut input.as_bytes()).unwrap(), 10500);
+    }
+
+    #[test]
+    fn aggregates_food_types() {
+        let input = "# Alice\n1000 apple\n2000 trail mix\n\n3000\n\n# Bob\n4000 apple\n500 apple";
+        let elves = day01::Day01.parse(&mut input.as_bytes()).unwrap();
+        assert_eq!(day01::food_aggregates(&elves), vec![
+            day01::FoodAggregate { name: None, items: 1, calories: 3000, carriers: 1 },
+            day01::FoodAggregate { name: Some("apple".to_string()), items: 3, calories: 5500, carriers: 2 },
+            day01::FoodAggregate { name: Some("trail mix".to_string()), items: 1, calories: 2000, carriers: 1 }
+        ]);
+    }
+
+    #[test]
+    fn reports_malformed_labelled_inventory() {
+        for input in ["# Alice\n1000\n#\n\n2000", "1000 apple\n\n# Bob\nten apples"] {
+            let parsed = day01::Day01.parse(&mut input.as_bytes()).unwrap_err();
+            let streamed = day01::stream_top_totals(&mut input.as_bytes(), 1).unwrap_err();
+            assert_eq!(parsed, streamed);
+        }
+        assert_eq!(day01::Day01.parse(&mut "# \n1000".as_bytes()).unwrap_err(), ParseError::new(1, 2, "an elf name"));
+        assert_eq!(day01::Day01.parse(&mut "1000\n# Alice".as_bytes()).unwrap_err(), ParseError::new(2, 1, "a calorie count"));
+    }
+}
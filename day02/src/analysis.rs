@@ -0,0 +1,142 @@
+use crate::{day02::{get_move_rule, get_round_score, Letter, Move, RoundResult}, inference::Interpretation};
+
+const MOVES: [Move; 3] = [Move::Rock, Move::Paper, Move::Scisors];
+
+/// The move scoring the most against `opponent`, among the ones reaching each possible result.
+pub fn best_response(opponent: Move) -> Move {
+    let rule = get_move_rule(opponent);
+    return [RoundResult::Win, RoundResult::Draw, RoundResult::Lose].iter()
+        .map(|result| rule.reason_opponent_move(*result))
+        .max_by_key(|response| get_round_score(opponent, *response))
+        .unwrap();
+}
+
+/// Probabilities of playing each move.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MixedStrategy {
+    rock: f64,
+    paper: f64,
+    scissors: f64
+}
+
+impl MixedStrategy {
+    /// Fails unless the probabilities are between 0 and 1 and add up to 1.
+    pub fn new(rock: f64, paper: f64, scissors: f64) -> Result<MixedStrategy, String> {
+        let probabilities = [rock, paper, scissors];
+        if probabilities.iter().any(|p| !(0.0..=1.0).contains(p)) {
+            return Err(format!("probabilities must be between 0 and 1: {:?}", probabilities));
+        }
+        let sum: f64 = probabilities.iter().sum();
+        if (sum - 1.0).abs() > 1e-9 {
+            return Err(format!("probabilities must add up to 1, not {}", sum));
+        }
+        return Ok(MixedStrategy { rock, paper, scissors });
+    }
+
+    pub fn uniform() -> MixedStrategy {
+        return MixedStrategy { rock: 1.0 / 3.0, paper: 1.0 / 3.0, scissors: 1.0 / 3.0 };
+    }
+
+    pub fn probability(&self, m: Move) -> f64 {
+        return match m {
+            Move::Rock => self.rock,
+            Move::Paper => self.paper,
+            Move::Scisors => self.scissors
+        };
+    }
+
+    /// Expected score of a single round against `opponent`.
+    pub fn expected_round_score(&self, opponent: Move) -> f64 {
+        return MOVES.iter().map(|m| self.probability(*m) * get_round_score(opponent, *m) as f64).sum();
+    }
+}
+
+/// Expected total score of playing `strategy` in every round against the given opponent moves.
+pub fn expected_score(opponents: &[Move], strategy: &MixedStrategy) -> f64 {
+    return opponents.iter().map(|opponent| strategy.expected_round_score(*opponent)).sum();
+}
+
+/// How the guide compares with best responding to every opponent move.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuideAnalysis {
+    pub best_responses: Vec<Move>,
+    pub optimal_score: i32,
+    pub predictions_score: i32,
+    pub elf_guide_score: i32,
+    /// Rounds in which following the elf guide scores less than the best response.
+    pub elf_guide_suboptimal_rounds: usize
+}
+
+impl GuideAnalysis {
+    /// Points the elf guide leaves on the table.
+    pub fn elf_guide_gap(&self) -> i32 {
+        return self.optimal_score - self.elf_guide_score;
+    }
+
+    /// Share of the optimal score reached by the elf guide, 1 for an empty guide.
+    pub fn elf_guide_efficiency(&self) -> f64 {
+        if self.optimal_score == 0 {
+            return 1.0;
+        }
+        return self.elf_guide_score as f64 / self.optimal_score as f64;
+    }
+}
+
+pub fn analyse_rounds(rounds: &[(Move, Letter)]) -> GuideAnalysis {
+    let best_responses = rounds.iter().map(|(opponent, _)| best_response(*opponent)).collect::<Vec<Move>>();
+    let optimal_scores = rounds.iter().zip(best_responses.iter())
+        .map(|((opponent, _), response)| get_round_score(*opponent, *response))
+        .collect::<Vec<i32>>();
+    let scores_reading = |interpretation: Interpretation| rounds.iter()
+        .map(|(opponent, letter)| interpretation.get_round_score(*opponent, *letter))
+        .collect::<Vec<i32>>();
+    let elf_guide_scores = scores_reading(Interpretation::ELF_GUIDE);
+
+    return GuideAnalysis {
+        best_responses,
+        optimal_score: optimal_scores.iter().sum(),
+        predictions_score: scores_reading(Interpretation::PREDICTIONS).iter().sum(),
+        elf_guide_score: elf_guide_scores.iter().sum(),
+        elf_guide_suboptimal_rounds: elf_guide_scores.iter().zip(optimal_scores.iter()).filter(|(score, optimal)| score < optimal).count()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analysis::{self, MixedStrategy}, day02::{Day02, Move}};
+    use aoc_common::solution::Solution;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn responds_with_highest_scoring_move() {
+        assert_eq!(analysis::best_response(Move::Rock), Move::Paper);
+        assert_eq!(analysis::best_response(Move::Paper), Move::Scisors);
+        assert_eq!(analysis::best_response(Move::Scisors), Move::Rock);
+    }
+
+    #[test]
+    fn computes_expected_score_of_mixed_strategy() {
+        let opponents = [Move::Rock, Move::Paper, Move::Scisors];
+        assert_eq!(analysis::expected_score(&opponents, &MixedStrategy::new(0.0, 1.0, 0.0).unwrap()), 8.0 + 5.0 + 2.0);
+        assert!((analysis::expected_score(&opponents, &MixedStrategy::uniform()) - 15.0).abs() < 1e-9);
+        assert!((MixedStrategy::new(0.5, 0.0, 0.5).unwrap().expected_round_score(Move::Rock) - (4.0 + 3.0) / 2.0).abs() < 1e-9);
+        assert!(MixedStrategy::new(0.5, 0.6, -0.1).is_err());
+        assert!(MixedStrategy::new(0.5, 0.2, 0.2).is_err());
+    }
+
+    #[test]
+    fn measures_distance_of_guide_from_optimal() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let analysis = analysis::analyse_rounds(&Day02.parse(&mut f).unwrap());
+        assert_eq!(analysis.best_responses, vec![Move::Paper, Move::Scisors, Move::Rock]);
+        assert_eq!((analysis.optimal_score, analysis.predictions_score, analysis.elf_guide_score), (24, 15, 12));
+        assert_eq!((analysis.elf_guide_gap(), analysis.elf_guide_suboptimal_rounds), (12, 2));
+        assert_eq!(analysis.elf_guide_efficiency(), 0.5);
+        assert_eq!(analysis::analyse_rounds(&[]).elf_guide_efficiency(), 1.0);
+
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        let analysis = analysis::analyse_rounds(&Day02.parse(&mut f).unwrap());
+        assert_eq!(analysis.elf_guide_score, 14416);
+        assert!(analysis.optimal_score >= analysis.elf_guide_score && analysis.optimal_score >= analysis.predictions_score);
+    }
+}
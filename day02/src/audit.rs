@@ -0,0 +1,114 @@
+use json::{object, JsonValue};
+use crate::{day02::{get_move_rule, get_move_score, get_round_result_score, Letter, Move, RoundResult}, inference::Interpretation};
+
+/// How a single round of a guide was scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditedRound {
+    /// 1-based number of the round, which is also its line in the guide.
+    pub round: usize,
+    pub opponent: Move,
+    pub player: Move,
+    /// Result for the second player.
+    pub result: RoundResult,
+    pub move_score: i32,
+    pub outcome_score: i32,
+    /// Total score up to and including this round.
+    pub running_total: i32
+}
+
+impl AuditedRound {
+    pub fn get_round_score(&self) -> i32 {
+        return self.move_score + self.outcome_score;
+    }
+}
+
+/// Scores every round of a guide read with `interpretation`, see [`Interpretation::PREDICTIONS`] and [`Interpretation::ELF_GUIDE`].
+pub fn audit_rounds(rounds: &[(Move, Letter)], interpretation: &Interpretation) -> Vec<AuditedRound> {
+    let mut running_total = 0;
+    return rounds.iter()
+        .enumerate()
+        .map(|(i, (opponent, letter))| {
+            let (opponent, player) = (*opponent, interpretation.decode(*opponent, *letter));
+            let move_score = get_move_score(player);
+            let outcome_score = get_round_result_score(opponent, player);
+            running_total += move_score + outcome_score;
+            return AuditedRound {
+                round: i + 1,
+                opponent,
+                player,
+                result: get_move_rule(player).get_round_result(opponent),
+                move_score,
+                outcome_score,
+                running_total
+            };
+        })
+        .collect();
+}
+
+/// One line per round after a header line.
+pub fn to_csv(rounds: &[AuditedRound]) -> String {
+    let mut lines = vec!["round,opponent,player,result,move_score,outcome_score,running_total".to_string()];
+    lines.extend(rounds.iter().map(|round| format!("{},{},{},{},{},{},{}",
+        round.round, round.opponent.name(), round.player.name(), round.result.name(), round.move_score, round.outcome_score, round.running_total)));
+    return lines.join("\n") + "\n";
+}
+
+/// An array with one object per round.
+pub fn to_json(rounds: &[AuditedRound]) -> JsonValue {
+    return rounds.iter()
+        .map(|round| object! {
+            round: round.round,
+            opponent: round.opponent.name(),
+            player: round.player.name(),
+            result: round.result.name(),
+            move_score: round.move_score,
+            outcome_score: round.outcome_score,
+            running_total: round.running_total
+        })
+        .collect::<Vec<JsonValue>>()
+        .into();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{audit, day02::{Day02, Letter, Move, RoundResult}, inference::Interpretation};
+    use aoc_common::solution::Solution;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn audits_sample_guide() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let rounds = audit::audit_rounds(&Day02.parse(&mut f).unwrap(), &Interpretation::ELF_GUIDE);
+        assert_eq!(rounds[1], audit::AuditedRound {
+            round: 2,
+            opponent: Move::Paper,
+            player: Move::Rock,
+            result: RoundResult::Lose,
+            move_score: 1,
+            outcome_score: 0,
+            running_total: 5
+        });
+        assert_eq!(rounds.last().map(|round| round.running_total), Some(12));
+    }
+
+    #[test]
+    fn totals_match_final_scores() {
+        for (interpretation, total) in [(Interpretation::PREDICTIONS, 15632), (Interpretation::ELF_GUIDE, 14416)] {
+            let mut f = BufReader::new(File::open("./day.input").unwrap());
+            let rounds = audit::audit_rounds(&Day02.parse(&mut f).unwrap(), &interpretation);
+            assert_eq!(rounds.last().unwrap().running_total, total);
+            assert_eq!(rounds.iter().map(|round| round.get_round_score()).sum::<i32>(), total);
+        }
+    }
+
+    #[test]
+    fn exports_audit_trail() {
+        let rounds = audit::audit_rounds(&[(Move::Rock, Letter::Y), (Move::Scisors, Letter::X)], &Interpretation::PREDICTIONS);
+        assert_eq!(audit::to_csv(&rounds), "round,opponent,player,result,move_score,outcome_score,running_total\n\
+            1,Rock,Paper,Win,2,6,8\n\
+            2,Scissors,Rock,Win,1,6,15\n");
+        let json = audit::to_json(&rounds);
+        assert_eq!(json.len(), 2);
+        assert_eq!(json[1].dump(), "{\"round\":2,\"opponent\":\"Scissors\",\"player\":\"Rock\",\"result\":\"Win\",\"move_score\":1,\"outcome_score\":6,\"running_total\":15}");
+    }
+}
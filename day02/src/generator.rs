@@ -0,0 +1,50 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub rounds: usize
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { rounds: 2500 };
+    }
+}
+
+/// A strategy guide of random rounds, scored with modular arithmetic instead of rule tables.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let rounds = (0..params.rounds)
+        .map(|_| (rng.below(3) as u32, rng.below(3) as u32))
+        .collect::<Vec<(u32, u32)>>();
+
+    let input = rounds.iter()
+        .map(|(opponent, column)| format!("{} {}", letter('A', *opponent), letter('X', *column)))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let predictions: u32 = rounds.iter()
+        .map(|(opponent, me)| me + 1 + (me + 4 - opponent) % 3 * 3)
+        .sum();
+    let elf_guide: u32 = rounds.iter()
+        .map(|(opponent, outcome)| (opponent + outcome + 2) % 3 + 1 + outcome * 3)
+        .sum();
+
+    return GeneratedInput { input, part1: Some(predictions.to_string()), part2: Some(elf_guide.to_string()) };
+}
+
+fn letter(first: char, offset: u32) -> char {
+    return char::from_u32(first as u32 + offset).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day02::Day02, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day02), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+use crate::day02::{get_move_rule, get_round_score, Letter, Move, RoundResult};
+
+/// What `X`, `Y` and `Z` stand for in the second column of a guide, in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    Moves([Move; 3]),
+    Results([RoundResult; 3])
+}
+
+impl Interpretation {
+    /// The reading of part 1: `X` rock, `Y` paper, `Z` scissors.
+    pub const PREDICTIONS: Interpretation = Interpretation::Moves([Move::Rock, Move::Paper, Move::Scisors]);
+
+    /// The reading of part 2: `X` lose, `Y` draw, `Z` win.
+    pub const ELF_GUIDE: Interpretation = Interpretation::Results([RoundResult::Lose, RoundResult::Draw, RoundResult::Win]);
+
+    /// All twelve ways to read the second column, those of the puzzle first.
+    pub fn all() -> Vec<Interpretation> {
+        let moves = [Move::Rock, Move::Paper, Move::Scisors];
+        let results = [RoundResult::Lose, RoundResult::Draw, RoundResult::Win];
+        let orders = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        return orders.iter().map(|order| Interpretation::Moves(order.map(|i| moves[i])))
+            .chain(orders.iter().map(|order| Interpretation::Results(order.map(|i| results[i]))))
+            .collect();
+    }
+
+    /// Move of the second player for a round with `letter` in the second column.
+    pub fn decode(&self, opponent: Move, letter: Letter) -> Move {
+        return match self {
+            Interpretation::Moves(moves) => moves[letter.index()],
+            Interpretation::Results(results) => get_move_rule(opponent).reason_opponent_move(results[letter.index()])
+        };
+    }
+
+    /// Score of the second player for a round with `letter` in the second column.
+    pub fn get_round_score(&self, opponent: Move, letter: Letter) -> i32 {
+        return get_round_score(opponent, self.decode(opponent, letter));
+    }
+}
+
+impl fmt::Display for Interpretation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let meanings = match self {
+            Interpretation::Moves(moves) => moves.map(|m| m.name()),
+            Interpretation::Results(results) => results.map(|result| result.name())
+        };
+        return write!(f, "X={} Y={} Z={}", meanings[0], meanings[1], meanings[2]);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoding {
+    pub interpretation: Interpretation,
+    pub score: i32,
+    /// Whether the score is the claimed one; always true without a claim.
+    pub consistent: bool
+}
+
+/// Scores a guide under every interpretation of its second column.
+pub fn infer_decodings(rounds: &[(Move, Letter)], claimed_score: Option<i32>) -> Vec<Decoding> {
+    return Interpretation::all()
+        .into_iter()
+        .map(|interpretation| {
+            let score = rounds.iter()
+                .map(|(opponent, letter)| interpretation.get_round_score(*opponent, *letter))
+                .sum();
+            return Decoding { interpretation, score, consistent: claimed_score.is_none_or(|claimed| claimed == score) };
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day02::{Day02, Letter, Move, RoundResult}, inference::{self, Interpretation}};
+    use aoc_common::solution::Solution;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn scores_every_interpretation() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let decodings = inference::infer_decodings(&Day02.parse(&mut f).unwrap(), None);
+        assert_eq!(decodings.len(), 12);
+        assert!(decodings.iter().all(|decoding| decoding.consistent));
+        assert_eq!((decodings[0].interpretation, decodings[0].score), (Interpretation::PREDICTIONS, 15));
+        assert_eq!((decodings[6].interpretation, decodings[6].score), (Interpretation::ELF_GUIDE, 12));
+    }
+
+    #[test]
+    fn finds_interpretations_matching_claimed_score() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        let decodings = inference::infer_decodings(&Day02.parse(&mut f).unwrap(), Some(14416));
+        let consistent = decodings.iter().filter(|decoding| decoding.consistent).map(|decoding| decoding.interpretation).collect::<Vec<Interpretation>>();
+        assert_eq!(consistent, vec![Interpretation::ELF_GUIDE]);
+
+        let decodings = inference::infer_decodings(&[(Move::Rock, Letter::X)], Some(4));
+        let consistent = decodings.iter().filter(|decoding| decoding.consistent).map(|decoding| decoding.interpretation.to_string()).collect::<Vec<String>>();
+        assert_eq!(consistent, vec!["X=Rock Y=Paper Z=Scissors", "X=Rock Y=Scissors Z=Paper", "X=Draw Y=Lose Z=Win", "X=Draw Y=Win Z=Lose"]);
+    }
+
+    #[test]
+    fn scores_rounds_under_interpretation() {
+        let swapped = Interpretation::Results([RoundResult::Win, RoundResult::Draw, RoundResult::Lose]);
+        assert_eq!(swapped.get_round_score(Move::Rock, Letter::X), 2 + 6);
+        assert_eq!(Interpretation::PREDICTIONS.get_round_score(Move::Rock, Letter::Z), 3);
+        assert_eq!(Interpretation::ELF_GUIDE.decode(Move::Rock, Letter::X), Move::Scisors);
+    }
+}
@@ -0,0 +1,297 @@
+pub mod analysis;
+pub mod audit;
+pub mod generator;
+pub mod inference;
+pub mod multiplayer;
+pub mod rules;
+pub mod tournament;
+
+pub mod day02 {
+    use std::io::BufRead;
+    use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+
+    pub fn get_total_score_according_to_predictions(input: &mut dyn BufRead) -> Result<i32, ParseError> {
+        return Day02.part1(&Day02.parse(input)?);
+    }
+
+    pub fn get_total_score_according_to_elf_guide(input: &mut dyn BufRead) -> Result<i32, ParseError> {
+        return Day02.part2(&Day02.parse(input)?);
+    }
+
+    pub struct Day02;
+
+    impl Solution for Day02 {
+        type Parsed = Vec<(Move, Letter)>;
+        type Answer1 = i32;
+        type Answer2 = i32;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<(Move, Letter)>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, rounds: &Vec<(Move, Letter)>) -> Result<i32, ParseError> {
+            return Ok(rounds
+                .iter()
+                .map(|(p1, p2)| get_round_moves_according_to_predictions(*p1, *p2))
+                .map(|(p1, p2)| get_round_score(p1, p2))
+                .sum());
+        }
+
+        fn part2(&self, rounds: &Vec<(Move, Letter)>) -> Result<i32, ParseError> {
+            return Ok(rounds
+                .iter()
+                .map(|(p1, p2)| get_round_moves_according_to_elf_guide(*p1, *p2))
+                .map(|(p1, p2)| get_round_score(p1, p2))
+                .sum());
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<(Move, Letter)>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines)
+            .iter()
+            .map(|round| parse_round(round))
+            .collect();
+    }
+
+    fn parse_round(round: &Line) -> Result<(Move, Letter), ParseError> {
+        let (p1, p2) = round.text.split_once(' ')
+            .ok_or_else(|| round.error_at(round.end_column(), "a space between the two columns"))?;
+        let opponent = [Move::Rock, Move::Paper, Move::Scisors][parse_column(round, p1, &['A', 'B', 'C'], "A, B or C")?];
+        let letter = [Letter::X, Letter::Y, Letter::Z][parse_column(round, p2, &['X', 'Y', 'Z'], "X, Y or Z")?];
+        return Ok((opponent, letter));
+    }
+
+    /// Position of the single letter of `column` among `allowed`.
+    fn parse_column(round: &Line, column: &str, allowed: &[char], expected: &str) -> Result<usize, ParseError> {
+        let mut chars = column.chars();
+        return match (chars.next(), chars.next()) {
+            (Some(c), None) if allowed.contains(&c) => Ok(allowed.iter().position(|letter| *letter == c).unwrap()),
+            _ => Err(round.error_on(column, expected))
+        };
+    }
+
+    /// Score of the second player for a single round.
+    pub fn get_round_score(p1: Move, p2: Move) -> i32 {
+        let player_two_move_score: i32 = get_move_score(p2);
+        let round_score: i32 = get_round_result_score(p1, p2);
+        return player_two_move_score + round_score
+    }
+
+    pub fn get_move_score(player_move: Move) -> i32 {
+        match player_move {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scisors => 3
+        }
+    }
+
+    pub fn get_round_result_score(p1: Move, p2: Move) -> i32 {
+        let rule = get_move_rule(p2);
+        let round_result = rule.get_round_result(p1);
+        match round_result {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Lose => 0
+        }
+    }
+
+    pub fn get_move_rule(player_move: Move) -> GameRule {
+        match player_move {
+            Move::Rock => GameRule::new(player_move, vec![Move::Scisors], vec![Move::Paper]),
+            Move::Paper => GameRule::new(player_move, vec![Move::Rock], vec![Move::Scisors]),
+            Move::Scisors => GameRule::new(player_move, vec![Move::Paper], vec![Move::Rock])
+        }
+    }
+
+    #[derive(Copy, Clone)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Move {
+        Rock,
+        Paper,
+        Scisors
+    }
+
+    impl Move {
+        /// Decodes a move letter of either column: `A`/`X` rock, `B`/`Y` paper, `C`/`Z` scissors.
+        pub fn from_letter(letter: char) -> Option<Move> {
+            return match letter {
+                'A' | 'X' => Some(Move::Rock),
+                'B' | 'Y' => Some(Move::Paper),
+                'C' | 'Z' => Some(Move::Scisors),
+                _ => None
+            };
+        }
+
+        pub fn name(&self) -> &'static str {
+            return match self {
+                Move::Rock => "Rock",
+                Move::Paper => "Paper",
+                Move::Scisors => "Scissors"
+            };
+        }
+    }
+
+    /// Letter of the second column of a guide, a move to play in part 1 and a result to reach in part 2.
+    #[derive(Copy, Clone)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum Letter {
+        X,
+        Y,
+        Z
+    }
+
+    impl Letter {
+        /// Position of the letter among `X`, `Y` and `Z`.
+        pub fn index(&self) -> usize {
+            return match self {
+                Letter::X => 0,
+                Letter::Y => 1,
+                Letter::Z => 2
+            };
+        }
+
+        /// `X` rock, `Y` paper, `Z` scissors.
+        pub fn as_move(&self) -> Move {
+            return [Move::Rock, Move::Paper, Move::Scisors][self.index()];
+        }
+
+        /// `X` lose, `Y` draw, `Z` win.
+        pub fn as_result(&self) -> RoundResult {
+            return [RoundResult::Lose, RoundResult::Draw, RoundResult::Win][self.index()];
+        }
+    }
+
+    #[derive(Copy, Clone)]
+    #[derive(Debug, PartialEq, Eq)]
+    pub enum RoundResult {
+        Win,
+        Lose,
+        Draw
+    }
+
+    impl RoundResult {
+        /// Decodes the second column of the elf guide: `X` lose, `Y` draw, `Z` win.
+        pub fn from_letter(letter: char) -> Option<RoundResult> {
+            return match letter {
+                'X' => Some(RoundResult::Lose),
+                'Y' => Some(RoundResult::Draw),
+                'Z' => Some(RoundResult::Win),
+                _ => None
+            };
+        }
+
+        pub fn name(&self) -> &'static str {
+            return match self {
+                RoundResult::Win => "Win",
+                RoundResult::Lose => "Lose",
+                RoundResult::Draw => "Draw"
+            };
+        }
+    }
+
+    /// What a move beats and loses to; moves are the classic ones unless the rule comes from a [`RuleSet`](crate::rules::RuleSet).
+    pub struct GameRule<M = Move> {
+        player: M,
+        stronger_than: Vec<M>,
+        weaker_than: Vec<M>
+    }
+
+    impl<M: Copy + PartialEq> GameRule<M> {
+        pub fn new(player: M, stronger_than: Vec<M>, weaker_than: Vec<M>) -> GameRule<M> {
+            GameRule {
+                player,
+                stronger_than,
+                weaker_than
+            }
+        }
+
+        pub fn get_round_result(&self, opponent: M) -> RoundResult {
+            if self.stronger_than.contains(&opponent) {
+                return RoundResult::Win;
+            }
+            else if self.weaker_than.contains(&opponent) {
+                return RoundResult::Lose;
+            }
+            else {
+                return RoundResult::Draw;
+            }
+        }
+
+        /// Every move the opponent can play to get `result`.
+        pub fn reason_opponent_moves(&self, result: RoundResult) -> Vec<M> {
+            match result {
+                RoundResult::Win => self.weaker_than.clone(),
+                RoundResult::Draw => vec![self.player],
+                RoundResult::Lose => self.stronger_than.clone()
+            }
+        }
+
+        /// The first move the opponent can play to get `result`.
+        pub fn reason_opponent_move(&self, result: RoundResult) -> M {
+            return self.reason_opponent_moves(result)[0];
+        }
+    }
+
+    /// Both moves of a round, reading the second column as the move to play.
+    pub fn get_round_moves_according_to_predictions(opponent: Move, letter: Letter) -> (Move, Move) {
+        return (opponent, letter.as_move());
+    }
+
+    /// Both moves of a round, reading the second column as the result to reach.
+    pub fn get_round_moves_according_to_elf_guide(opponent: Move, letter: Letter) -> (Move, Move) {
+        let rule = get_move_rule(opponent);
+        let player = rule.reason_opponent_move(letter.as_result());
+
+        return (opponent, player);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day02;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day02::get_total_score_according_to_predictions(&mut f).unwrap(), 15);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day02::get_total_score_according_to_predictions(&mut f).unwrap(), 15632);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day02::get_total_score_according_to_elf_guide(&mut f).unwrap(), 12);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day02::get_total_score_according_to_elf_guide(&mut f).unwrap(), 14416);
+    }
+
+    #[test]
+    fn reports_unknown_move_letter() {
+        let error = day02::get_total_score_according_to_predictions(&mut "A Y\nB W".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "X, Y or Z"));
+        let error = day02::get_total_score_according_to_elf_guide(&mut "X Y".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 1, "A, B or C"));
+    }
+
+    #[test]
+    fn scores_rounds_through_library_api() {
+        let rule = day02::get_move_rule(day02::Move::Rock);
+        assert_eq!(rule.get_round_result(day02::Move::Scisors), day02::RoundResult::Win);
+        assert_eq!(rule.reason_opponent_move(day02::RoundResult::Lose), day02::Move::Scisors);
+        assert_eq!(day02::get_round_score(day02::Move::Rock, day02::Move::Paper), 8);
+        assert_eq!(day02::get_round_moves_according_to_predictions(day02::Move::Paper, day02::Letter::Z), (day02::Move::Paper, day02::Move::Scisors));
+        assert_eq!(day02::get_round_moves_according_to_elf_guide(day02::Move::Paper, day02::Letter::Z), (day02::Move::Paper, day02::Move::Scisors));
+    }
+}
@@ -0,0 +1,223 @@
+use std::{cmp::Reverse, io::BufRead};
+use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}};
+use crate::day02::{get_move_rule, get_move_score, Move, RoundResult};
+
+/// Reads one round per line, as the moves of every player separated by spaces, e.g. `A C A`.
+///
+/// Both letter columns of the puzzle are understood, and every round must have the same number of players, at least two.
+pub fn parse_rounds(input: &mut dyn BufRead) -> Result<Vec<Vec<Move>>, ParseError> {
+    let lines = read_input(input)?;
+    let rounds = number_lines(&lines)
+        .iter()
+        .map(parse_round)
+        .collect::<Result<Vec<Vec<Move>>, ParseError>>()?;
+
+    let players = rounds.first().map(|round| round.len()).unwrap_or(2);
+    if players < 2 {
+        return Err(ParseError::new(1, 2, "moves of at least two players"));
+    }
+    if let Some(i) = rounds.iter().position(|round| round.len() != players) {
+        let line = Line::new(i + 1, &lines[i]);
+        return Err(line.error_at(line.end_column(), format!("moves of {} players", players)));
+    }
+    return Ok(rounds);
+}
+
+fn parse_round(line: &Line) -> Result<Vec<Move>, ParseError> {
+    return line.text.split(' ')
+        .map(|letter| {
+            let mut chars = letter.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) => Move::from_letter(c),
+                _ => None
+            }.ok_or_else(|| line.error_on(letter, "A, B, C, X, Y or Z"));
+        })
+        .collect();
+}
+
+/// Result of every player of a round.
+///
+/// A round has winners and losers only when exactly two different moves are thrown; otherwise everybody draws.
+pub fn resolve_round(moves: &[Move]) -> Vec<RoundResult> {
+    let distinct = moves.iter().fold(Vec::<Move>::new(), |mut distinct, m| {
+        if !distinct.contains(m) {
+            distinct.push(*m);
+        }
+        return distinct;
+    });
+    if distinct.len() != 2 {
+        return vec![RoundResult::Draw; moves.len()];
+    }
+    return moves.iter()
+        .map(|m| {
+            let other = if *m == distinct[0] { distinct[1] } else { distinct[0] };
+            return get_move_rule(*m).get_round_result(other);
+        })
+        .collect();
+}
+
+/// Points of a player for a round of several players.
+pub trait ScoringScheme {
+    fn score(&self, own: Move, result: RoundResult, moves: &[Move]) -> i32;
+}
+
+/// The puzzle's scoring: the move score plus 6 for a win, 3 for a draw and nothing for a loss.
+pub struct PuzzleScoring;
+
+impl ScoringScheme for PuzzleScoring {
+    fn score(&self, own: Move, result: RoundResult, _moves: &[Move]) -> i32 {
+        let outcome = match result {
+            RoundResult::Win => 6,
+            RoundResult::Draw => 3,
+            RoundResult::Lose => 0
+        };
+        return get_move_score(own) + outcome;
+    }
+}
+
+/// The winners of a round share `pot` points, rounded down; nobody scores in a draw.
+pub struct SharedPot {
+    pub pot: i32
+}
+
+impl ScoringScheme for SharedPot {
+    fn score(&self, _own: Move, result: RoundResult, moves: &[Move]) -> i32 {
+        if result != RoundResult::Win {
+            return 0;
+        }
+        let winners = resolve_round(moves).iter().filter(|result| **result == RoundResult::Win).count();
+        return self.pot / winners as i32;
+    }
+}
+
+/// Number of players of the rounds, 0 without rounds; fails unless every round has as many moves as the first one.
+pub fn count_players(rounds: &[Vec<Move>]) -> Result<usize, String> {
+    let players = rounds.first().map(|round| round.len()).unwrap_or(0);
+    return match rounds.iter().position(|round| round.len() != players) {
+        Some(i) => Err(format!("round {} has {} players, not {} like round 1", i + 1, rounds[i].len(), players)),
+        None => Ok(players)
+    };
+}
+
+/// Total score of every player over all rounds.
+pub fn score_rounds(rounds: &[Vec<Move>], scheme: &dyn ScoringScheme) -> Result<Vec<i32>, String> {
+    let mut totals = vec![0; count_players(rounds)?];
+    for round in rounds.iter() {
+        for (player, result) in resolve_round(round).into_iter().enumerate() {
+            totals[player] += scheme.score(round[player], result, round);
+        }
+    }
+    return Ok(totals);
+}
+
+/// A player's record in a match; players are numbered from 1 in the order of their moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchStanding {
+    pub player: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub score: i32
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchReport {
+    pub rounds_played: usize,
+    /// Best player first.
+    pub standings: Vec<MatchStanding>,
+    /// `None` when the best players are tied on every tie-breaker.
+    pub winner: Option<usize>
+}
+
+/// Plays the rounds of a best-of-`best_of` match in order.
+///
+/// The match stops once a player has won more than half of `best_of` rounds, after `best_of` rounds or when
+/// the rounds run out. Players are ranked by rounds won, then by score, then by fewest rounds lost.
+pub fn play_match(rounds: &[Vec<Move>], best_of: usize, scheme: &dyn ScoringScheme) -> Result<MatchReport, String> {
+    let players = count_players(rounds)?;
+    let mut standings = (1..=players)
+        .map(|player| MatchStanding { player, wins: 0, draws: 0, losses: 0, score: 0 })
+        .collect::<Vec<MatchStanding>>();
+
+    let mut rounds_played = 0;
+    for round in rounds.iter().take(best_of) {
+        for (standing, result) in standings.iter_mut().zip(resolve_round(round)) {
+            standing.score += scheme.score(round[standing.player - 1], result, round);
+            match result {
+                RoundResult::Win => standing.wins += 1,
+                RoundResult::Draw => standing.draws += 1,
+                RoundResult::Lose => standing.losses += 1
+            }
+        }
+        rounds_played += 1;
+        if standings.iter().any(|standing| standing.wins > best_of / 2) {
+            break;
+        }
+    }
+
+    let rank = |standing: &MatchStanding| (Reverse(standing.wins), Reverse(standing.score), standing.losses);
+    standings.sort_by_key(rank);
+    let winner = match standings.as_slice() {
+        [first, second, ..] if rank(first) == rank(second) => None,
+        [first, ..] => Some(first.player),
+        [] => None
+    };
+    return Ok(MatchReport { rounds_played, standings, winner });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day02::{Move, RoundResult}, multiplayer::{self, PuzzleScoring, SharedPot}};
+    use aoc_common::parse::ParseError;
+
+    #[test]
+    fn reads_rounds_of_several_players() {
+        let rounds = multiplayer::parse_rounds(&mut "A Y C\nX X B".as_bytes()).unwrap();
+        assert_eq!(rounds, vec![vec![Move::Rock, Move::Paper, Move::Scisors], vec![Move::Rock, Move::Rock, Move::Paper]]);
+        assert_eq!(multiplayer::parse_rounds(&mut "A B C\nA B".as_bytes()), Err(ParseError::new(2, 4, "moves of 3 players")));
+        assert_eq!(multiplayer::parse_rounds(&mut "A B D".as_bytes()), Err(ParseError::new(1, 5, "A, B, C, X, Y or Z")));
+        assert_eq!(multiplayer::parse_rounds(&mut "A".as_bytes()), Err(ParseError::new(1, 2, "moves of at least two players")));
+    }
+
+    #[test]
+    fn resolves_rounds_with_two_distinct_moves_only() {
+        use RoundResult::{Draw, Lose, Win};
+        assert_eq!(multiplayer::resolve_round(&[Move::Rock, Move::Paper, Move::Rock]), vec![Lose, Win, Lose]);
+        assert_eq!(multiplayer::resolve_round(&[Move::Rock, Move::Paper, Move::Scisors]), vec![Draw, Draw, Draw]);
+        assert_eq!(multiplayer::resolve_round(&[Move::Paper, Move::Paper, Move::Paper]), vec![Draw, Draw, Draw]);
+    }
+
+    #[test]
+    fn scores_rounds_with_pluggable_schemes() {
+        let rounds = vec![vec![Move::Scisors, Move::Paper, Move::Scisors], vec![Move::Rock, Move::Paper, Move::Scisors]];
+        assert_eq!(multiplayer::score_rounds(&rounds, &PuzzleScoring), Ok(vec![(3 + 6) + (1 + 3), 2 + (2 + 3), (3 + 6) + (3 + 3)]));
+        assert_eq!(multiplayer::score_rounds(&rounds, &SharedPot { pot: 10 }), Ok(vec![5, 0, 5]));
+        assert_eq!(multiplayer::score_rounds(&[vec![Move::Rock, Move::Rock]], &PuzzleScoring), Ok(vec![4, 4]));
+    }
+
+    #[test]
+    fn rejects_rounds_with_different_numbers_of_players() {
+        let rounds = vec![vec![Move::Rock, Move::Paper], vec![Move::Rock, Move::Paper, Move::Scisors]];
+        let error = "round 2 has 3 players, not 2 like round 1".to_string();
+        assert_eq!(multiplayer::score_rounds(&rounds, &PuzzleScoring), Err(error.clone()));
+        assert_eq!(multiplayer::play_match(&rounds, 3, &PuzzleScoring), Err(error));
+        assert_eq!(multiplayer::count_players(&rounds[..1]), Ok(2));
+        assert_eq!(multiplayer::count_players(&[]), Ok(0));
+    }
+
+    #[test]
+    fn plays_best_of_matches() {
+        let rounds = multiplayer::parse_rounds(&mut "A B A\nB B C\nC A C\nA A A\nA B B".as_bytes()).unwrap();
+        let report = multiplayer::play_match(&rounds, 3, &PuzzleScoring).unwrap();
+        assert_eq!(report.rounds_played, 3);
+        assert_eq!(report.winner, Some(2));
+        assert_eq!(report.standings.iter().map(|standing| (standing.player, standing.wins, standing.losses)).collect::<Vec<(usize, usize, usize)>>(),
+            vec![(2, 2, 1), (3, 1, 2), (1, 0, 3)]);
+
+        let report = multiplayer::play_match(&rounds[..1], 5, &SharedPot { pot: 2 }).unwrap();
+        assert_eq!((report.rounds_played, report.winner), (1, Some(2)));
+
+        let report = multiplayer::play_match(&rounds[3..4], 1, &PuzzleScoring).unwrap();
+        assert_eq!(report.winner, None);
+    }
+}
@@ -0,0 +1,422 @@
+use std::{cmp::Reverse, io::BufRead};
+use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+use serde::Deserialize;
+use crate::day02::{GameRule, RoundResult};
+
+const CLASSIC: &str = include_str!("../rules/classic.toml");
+const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = include_str!("../rules/rpsls.toml");
+
+/// A move of a rule set, identified by its position in [`RuleSet::get_moves`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoveDefinition {
+    pub name: String,
+    pub score: i32,
+    /// Letter of the move in the first column of a guide.
+    pub opponent_letter: char,
+    /// Letter of the move in the second column when it is read as the move to play.
+    pub player_letter: char
+}
+
+/// Moves, what each of them beats, how rounds are scored and how guides encode them.
+///
+/// A rule set solves the puzzle in place of [`Day02`](crate::day02::Day02); the analyses, audits and
+/// tournaments of this crate are played with the classic moves only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    name: String,
+    moves: Vec<MoveDefinition>,
+    /// Moves beaten by each move.
+    beats: Vec<Vec<usize>>,
+    outcome_scores: OutcomeScores,
+    result_letters: ResultLetters
+}
+
+/// A rule set as written in TOML, with one `[[move]]` table per move.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    name: String,
+    #[serde(default)]
+    outcome_scores: OutcomeScores,
+    #[serde(default)]
+    result_letters: ResultLetters,
+    #[serde(rename = "move")]
+    moves: Vec<MoveConfig>
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MoveConfig {
+    name: String,
+    score: i32,
+    opponent: char,
+    player: char,
+    beats: Vec<String>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeScores {
+    pub win: i32,
+    pub draw: i32,
+    pub lose: i32
+}
+
+impl Default for OutcomeScores {
+    fn default() -> OutcomeScores {
+        return OutcomeScores { win: 6, draw: 3, lose: 0 };
+    }
+}
+
+/// Letters of the second column when it is read as the result to reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ResultLetters {
+    pub lose: char,
+    pub draw: char,
+    pub win: char
+}
+
+impl Default for ResultLetters {
+    fn default() -> ResultLetters {
+        return ResultLetters { lose: 'X', draw: 'Y', win: 'Z' };
+    }
+}
+
+impl RuleSet {
+    /// Rock, paper and scissors encoded as in the puzzle.
+    pub fn classic() -> RuleSet {
+        return RuleSet::from_toml(CLASSIC).expect("the classic rule set is valid");
+    }
+
+    /// Rock, paper, scissors, lizard and Spock, played with `V` to `Z` in the second column.
+    pub fn rock_paper_scissors_lizard_spock() -> RuleSet {
+        return RuleSet::from_toml(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).expect("the RPSLS rule set is valid");
+    }
+
+    pub fn from_toml(text: &str) -> Result<RuleSet, String> {
+        let config: Config = toml::from_str(text).map_err(|error| error.to_string())?;
+        let moves = config.moves.iter()
+            .map(|m| MoveDefinition { name: m.name.clone(), score: m.score, opponent_letter: m.opponent, player_letter: m.player })
+            .collect::<Vec<MoveDefinition>>();
+        let beats = config.moves.iter()
+            .map(|m| m.beats.iter()
+                .map(|beaten| moves.iter().position(|other| other.name == *beaten)
+                    .ok_or_else(|| format!("{} beats unknown move {}", m.name, beaten)))
+                .collect::<Result<Vec<usize>, String>>())
+            .collect::<Result<Vec<Vec<usize>>, String>>()?;
+        return RuleSet::new(config.name, moves, beats, config.outcome_scores, config.result_letters);
+    }
+
+    /// `n` moves in a circle, each beating the `(n - 1) / 2` moves before it, scored from 1 to `n`.
+    ///
+    /// Moves are lettered from `A` in the first column and up to `Z` in the second one.
+    pub fn balanced(n: usize) -> Result<RuleSet, String> {
+        if n < 3 || n.is_multiple_of(2) || n > 25 {
+            return Err(format!("a balanced rule set needs an odd number of moves from 3 to 25, not {}", n));
+        }
+        let moves = (0..n)
+            .map(|i| MoveDefinition {
+                name: format!("Move {}", i + 1),
+                score: i as i32 + 1,
+                opponent_letter: (b'A' + i as u8) as char,
+                player_letter: (b'Z' + 1 + i as u8 - n as u8) as char
+            })
+            .collect();
+        let beats = (0..n)
+            .map(|i| (1..=(n - 1) / 2).map(|k| (i + n - k) % n).collect())
+            .collect();
+        return RuleSet::new(format!("Balanced {}", n), moves, beats, OutcomeScores::default(), ResultLetters::default());
+    }
+
+    /// Checks that names and letters are unique and that every move beats and loses to at least one other move,
+    /// but never both to the same one.
+    pub fn new(name: String, moves: Vec<MoveDefinition>, beats: Vec<Vec<usize>>, outcome_scores: OutcomeScores, result_letters: ResultLetters) -> Result<RuleSet, String> {
+        if moves.len() < 2 {
+            return Err("a rule set needs at least two moves".to_string());
+        }
+        if beats.len() != moves.len() {
+            return Err(format!("{} moves but {} lists of beaten moves", moves.len(), beats.len()));
+        }
+        for (i, m) in moves.iter().enumerate() {
+            let earlier = &moves[..i];
+            if earlier.iter().any(|other| other.name == m.name) {
+                return Err(format!("move {} is defined twice", m.name));
+            }
+            if earlier.iter().any(|other| other.opponent_letter == m.opponent_letter) {
+                return Err(format!("opponent letter {} is used twice", m.opponent_letter));
+            }
+            if earlier.iter().any(|other| other.player_letter == m.player_letter) {
+                return Err(format!("player letter {} is used twice", m.player_letter));
+            }
+        }
+        if [result_letters.draw, result_letters.win].contains(&result_letters.lose) || result_letters.draw == result_letters.win {
+            return Err("result letters must differ".to_string());
+        }
+        for (i, beaten) in beats.iter().enumerate() {
+            let name = &moves[i].name;
+            if let Some(j) = beaten.iter().find(|j| **j >= moves.len()) {
+                return Err(format!("{} beats unknown move {}", name, j));
+            }
+            if beaten.contains(&i) {
+                return Err(format!("{} beats itself", name));
+            }
+            if let Some(j) = beaten.iter().find(|j| beats[**j].contains(&i)) {
+                return Err(format!("{} and {} beat each other", name, moves[*j].name));
+            }
+            if beaten.is_empty() {
+                return Err(format!("{} beats no move", name));
+            }
+            if !beats.iter().any(|other| other.contains(&i)) {
+                return Err(format!("{} loses to no move", name));
+            }
+        }
+        return Ok(RuleSet { name, moves, beats, outcome_scores, result_letters });
+    }
+
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_moves(&self) -> &[MoveDefinition] {
+        return &self.moves;
+    }
+
+    pub fn get_outcome_scores(&self) -> OutcomeScores {
+        return self.outcome_scores;
+    }
+
+    /// Whether every move beats exactly half of the other moves and loses to the rest.
+    pub fn is_balanced(&self) -> bool {
+        return self.beats.iter().all(|beaten| 2 * beaten.len() + 1 == self.moves.len());
+    }
+
+    pub fn get_move_rule(&self, player: usize) -> GameRule<usize> {
+        let weaker_than = (0..self.moves.len()).filter(|other| self.beats[*other].contains(&player)).collect();
+        return GameRule::new(player, self.beats[player].clone(), weaker_than);
+    }
+
+    pub fn get_outcome_score(&self, result: RoundResult) -> i32 {
+        return match result {
+            RoundResult::Win => self.outcome_scores.win,
+            RoundResult::Draw => self.outcome_scores.draw,
+            RoundResult::Lose => self.outcome_scores.lose
+        };
+    }
+
+    /// Score of the second player for a single round.
+    pub fn get_round_score(&self, opponent: usize, player: usize) -> i32 {
+        return self.moves[player].score + self.get_outcome_score(self.get_move_rule(player).get_round_result(opponent));
+    }
+
+    /// The move getting `result` against `opponent`; when several do, the one scoring the most, then the first defined.
+    pub fn respond(&self, opponent: usize, result: RoundResult) -> usize {
+        return self.get_move_rule(opponent).reason_opponent_moves(result)
+            .into_iter()
+            .max_by_key(|m| (self.moves[*m].score, Reverse(*m)))
+            .expect("every move of a rule set beats and loses to another one");
+    }
+
+    /// Total score of a guide whose second column is the move to play.
+    pub fn score_predictions(&self, input: &mut dyn BufRead) -> Result<i32, ParseError> {
+        return self.part1(&self.parse(input)?);
+    }
+
+    /// Total score of a guide whose second column is the result to reach, see [`RuleSet::respond`].
+    pub fn score_elf_guide(&self, input: &mut dyn BufRead) -> Result<i32, ParseError> {
+        return self.part2(&self.parse(input)?);
+    }
+
+    fn get_player_letters(&self) -> Vec<char> {
+        return self.moves.iter().map(|m| m.player_letter).collect();
+    }
+
+    fn get_result_letters(&self) -> Vec<char> {
+        return vec![self.result_letters.lose, self.result_letters.draw, self.result_letters.win];
+    }
+
+    /// Rounds as the index of the opponent's move and the index of the second letter in `second_letters`,
+    /// or the first round whose second letter is not one of them.
+    fn read_second_column(&self, rounds: &[(usize, char)], second_letters: &[char]) -> Result<Vec<(usize, usize)>, ParseError> {
+        return rounds.iter()
+            .enumerate()
+            .map(|(i, (opponent, letter))| second_letters.iter().position(|other| other == letter)
+                .map(|second| (*opponent, second))
+                .ok_or_else(|| ParseError::new(i + 1, 3, describe_letters(second_letters))))
+            .collect();
+    }
+
+    fn score_elf_guide_round(&self, opponent: usize, result: usize) -> i32 {
+        let results = [RoundResult::Lose, RoundResult::Draw, RoundResult::Win];
+        return self.get_round_score(opponent, self.respond(opponent, results[result]));
+    }
+}
+
+/// The puzzle played with a rule set: rounds are the index of the opponent's move and the second letter, which is
+/// either a move letter or a result letter until a part reads it.
+impl Solution for RuleSet {
+    type Parsed = Vec<(usize, char)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<(usize, char)>, ParseError> {
+        let lines = read_input(input)?;
+        let opponent_letters = self.moves.iter().map(|m| m.opponent_letter).collect::<Vec<char>>();
+        let mut second_letters = self.get_player_letters();
+        for letter in self.get_result_letters() {
+            if !second_letters.contains(&letter) {
+                second_letters.push(letter);
+            }
+        }
+        return number_lines(&lines)
+            .iter()
+            .map(|round| {
+                let (p1, p2) = round.text.split_once(' ')
+                    .ok_or_else(|| round.error_at(round.end_column(), "a space between the two columns"))?;
+                return Ok((parse_letter(round, p1, &opponent_letters)?, second_letters[parse_letter(round, p2, &second_letters)?]));
+            })
+            .collect();
+    }
+
+    fn part1(&self, rounds: &Vec<(usize, char)>) -> Result<i32, ParseError> {
+        return Ok(self.read_second_column(rounds, &self.get_player_letters())?
+            .iter()
+            .map(|(opponent, player)| self.get_round_score(*opponent, *player))
+            .sum());
+    }
+
+    fn part2(&self, rounds: &Vec<(usize, char)>) -> Result<i32, ParseError> {
+        return Ok(self.read_second_column(rounds, &self.get_result_letters())?
+            .iter()
+            .map(|(opponent, result)| self.score_elf_guide_round(*opponent, *result))
+            .sum());
+    }
+}
+
+fn parse_letter(round: &Line, column: &str, allowed: &[char]) -> Result<usize, ParseError> {
+    let mut chars = column.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(c), None) if allowed.contains(&c) => Ok(allowed.iter().position(|letter| *letter == c).unwrap()),
+        _ => Err(round.error_on(column, describe_letters(allowed)))
+    };
+}
+
+/// `A, B or C`
+fn describe_letters(letters: &[char]) -> String {
+    let names = letters.iter().map(|letter| letter.to_string()).collect::<Vec<String>>();
+    return match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day02::{self, RoundResult}, rules::RuleSet};
+    use aoc_common::{parse::ParseError, solution::{Answers, Solution, Solver}};
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn classic_rule_set_scores_like_the_puzzle() {
+        let rules = RuleSet::classic();
+        assert!(rules.is_balanced());
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(rules.score_predictions(&mut f), Ok(15632));
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(rules.score_elf_guide(&mut f), Ok(14416));
+        assert_eq!(RuleSet::balanced(3).unwrap().score_predictions(&mut "A Y\nB X\nC Z".as_bytes()), Ok(15));
+    }
+
+    #[test]
+    fn plays_rock_paper_scissors_lizard_spock() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        assert!(rules.is_balanced());
+        let spock = rules.get_move_rule(4);
+        assert_eq!(spock.get_round_result(0), RoundResult::Win);
+        assert_eq!(spock.get_round_result(3), RoundResult::Lose);
+        assert_eq!(spock.reason_opponent_moves(RoundResult::Win), vec![1, 3]);
+        assert_eq!(rules.respond(4, RoundResult::Win), 3);
+        assert_eq!(rules.score_predictions(&mut "E V\nD W".as_bytes()), Ok(3));
+        assert_eq!(rules.score_elf_guide(&mut "E Z\nA Y".as_bytes()), Ok(4 + 6 + 1 + 3));
+        assert_eq!(rules.score_predictions(&mut "A U".as_bytes()), Err(ParseError::new(1, 3, "V, W, X, Y or Z")));
+    }
+
+    #[test]
+    fn solves_the_puzzle_with_a_rule_set() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(rules.solve(&mut f), Ok(Answers { part1: "24".to_string(), part2: "20".to_string() }));
+
+        let rounds = rules.parse(&mut "A V\nC Z".as_bytes()).unwrap();
+        assert_eq!(rules.part1(&rounds), Ok(1 + 3 + 5 + 6));
+        assert_eq!(rules.part2(&rounds), Err(ParseError::new(1, 3, "X, Y or Z")));
+    }
+
+    #[test]
+    fn fails_the_part_that_cannot_read_a_letter() {
+        let rules = RuleSet::from_toml(r#"
+            name = "Lowercase"
+            [[move]]
+            name = "Up"
+            score = 1
+            opponent = "A"
+            player = "u"
+            beats = ["Side"]
+            [[move]]
+            name = "Down"
+            score = 2
+            opponent = "B"
+            player = "d"
+            beats = ["Up"]
+            [[move]]
+            name = "Side"
+            score = 3
+            opponent = "C"
+            player = "s"
+            beats = ["Down"]
+        "#).unwrap();
+        let rounds = rules.parse(&mut "A d\nB Z".as_bytes()).unwrap();
+        assert_eq!(rules.part1(&rounds), Err(ParseError::new(2, 3, "u, d or s")));
+        assert_eq!(rules.part2(&rounds), Err(ParseError::new(1, 3, "X, Y or Z")));
+        assert_eq!(rules.solve_part(&mut "A Z".as_bytes(), 1), Some(Err(ParseError::new(1, 3, "u, d or s"))));
+        assert_eq!(rules.solve_part(&mut "A Z".as_bytes(), 2), Some(Ok("8".to_string())));
+    }
+
+    #[test]
+    fn builds_balanced_rule_sets() {
+        let rules = RuleSet::balanced(7).unwrap();
+        assert!(rules.is_balanced());
+        assert_eq!((rules.get_moves()[0].opponent_letter, rules.get_moves()[0].player_letter), ('A', 'T'));
+        assert_eq!(rules.get_move_rule(0).reason_opponent_moves(RoundResult::Lose), vec![6, 5, 4]);
+        assert!(RuleSet::balanced(25).unwrap().is_balanced());
+        assert!(RuleSet::balanced(4).is_err());
+    }
+
+    #[test]
+    fn rejects_inconsistent_rule_sets() {
+        let rules = |moves: &str| RuleSet::from_toml(&format!("name = \"Test\"\n{}", moves));
+        let definition = |name: &str, letter: char, beats: &str| format!(
+            "[[move]]\nname = \"{}\"\nscore = 1\nopponent = \"{}\"\nplayer = \"{}\"\nbeats = [{}]\n",
+            name, letter, letter.to_ascii_lowercase(), beats);
+
+        let cycle = [definition("Up", 'A', "\"Down\""), definition("Down", 'B', "\"Side\""), definition("Side", 'C', "\"Up\"")].concat();
+        assert_eq!(rules(&cycle).map(|rules| rules.get_outcome_scores().win), Ok(6));
+        assert_eq!(rules(&[definition("Up", 'A', "\"Down\""), definition("Down", 'B', "\"Up\"")].concat()),
+            Err("Up and Down beat each other".to_string()));
+        assert_eq!(rules(&[definition("Up", 'A', "\"Left\""), definition("Down", 'B', "\"Up\"")].concat()),
+            Err("Up beats unknown move Left".to_string()));
+        assert_eq!(rules(&[definition("Up", 'A', "\"Down\""), definition("Down", 'B', "")].concat()),
+            Err("Up loses to no move".to_string()));
+        assert_eq!(rules(&[definition("Up", 'A', "\"Down\""), definition("Down", 'A', "\"Up\"")].concat()),
+            Err("opponent letter A is used twice".to_string()));
+        assert!(rules(&cycle.replace("score = 1", "score = \"one\"")).is_err());
+    }
+
+    #[test]
+    fn generalised_rule_keeps_classic_behaviour() {
+        let rule = day02::get_move_rule(day02::Move::Paper);
+        assert_eq!(rule.reason_opponent_moves(RoundResult::Win), vec![day02::Move::Scisors]);
+        assert_eq!(rule.reason_opponent_move(RoundResult::Draw), day02::Move::Paper);
+    }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+use aoc_common::generate::Rng;
+use crate::day02::{get_move_rule, get_move_score, get_round_result_score, Move, RoundResult};
+
+const MOVES: [Move; 3] = [Move::Rock, Move::Paper, Move::Scisors];
+
+/// One round of a match, seen by one of the players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub own: Move,
+    pub opponent: Move
+}
+
+/// A strategy choosing each move from the rounds played so far in the current match.
+pub trait Player {
+    fn name(&self) -> String;
+
+    fn play(&mut self, history: &[Round]) -> Move;
+}
+
+/// The move that beats `opponent`.
+fn counter(opponent: Move) -> Move {
+    return get_move_rule(opponent).reason_opponent_move(RoundResult::Win);
+}
+
+/// Plays the given moves in order, then keeps playing the last one.
+pub struct FixedSequence {
+    moves: Vec<Move>
+}
+
+impl FixedSequence {
+    pub fn new(moves: Vec<Move>) -> FixedSequence {
+        return FixedSequence { moves };
+    }
+}
+
+impl Player for FixedSequence {
+    fn name(&self) -> String {
+        return format!("fixed {:?}", self.moves);
+    }
+
+    fn play(&mut self, history: &[Round]) -> Move {
+        return self.moves.get(history.len()).or(self.moves.last()).copied().unwrap_or(Move::Rock);
+    }
+}
+
+/// Plays the given moves over and over.
+pub struct Cyclic {
+    moves: Vec<Move>
+}
+
+impl Cyclic {
+    pub fn new(moves: Vec<Move>) -> Cyclic {
+        return Cyclic { moves };
+    }
+}
+
+impl Player for Cyclic {
+    fn name(&self) -> String {
+        return format!("cyclic {:?}", self.moves);
+    }
+
+    fn play(&mut self, history: &[Round]) -> Move {
+        if self.moves.is_empty() {
+            return Move::Rock;
+        }
+        return self.moves[history.len() % self.moves.len()];
+    }
+}
+
+/// Counters the move the opponent played most often so far, the earliest of rock, paper and scissors on ties.
+///
+/// Plays rock in the first round.
+pub struct FrequencyAdaptive;
+
+impl Player for FrequencyAdaptive {
+    fn name(&self) -> String {
+        return "frequency adaptive".to_string();
+    }
+
+    fn play(&mut self, history: &[Round]) -> Move {
+        if history.is_empty() {
+            return Move::Rock;
+        }
+        let count = |m: &Move| history.iter().filter(|round| round.opponent == *m).count();
+        let favourite = MOVES.iter().rev().max_by_key(|m| count(m)).unwrap();
+        return counter(*favourite);
+    }
+}
+
+/// Repeats a winning move, otherwise plays the move that would have beaten the opponent's last one.
+pub struct WinStayLoseShift {
+    first: Move
+}
+
+impl WinStayLoseShift {
+    pub fn new(first: Move) -> WinStayLoseShift {
+        return WinStayLoseShift { first };
+    }
+}
+
+impl Player for WinStayLoseShift {
+    fn name(&self) -> String {
+        return "win-stay lose-shift".to_string();
+    }
+
+    fn play(&mut self, history: &[Round]) -> Move {
+        return match history.last() {
+            None => self.first,
+            Some(last) if get_move_rule(last.own).get_round_result(last.opponent) == RoundResult::Win => last.own,
+            Some(last) => counter(last.opponent)
+        };
+    }
+}
+
+/// Picks moves uniformly at random; the same seed always plays the same moves.
+pub struct SeededRandom {
+    seed: u64,
+    rng: Rng
+}
+
+impl SeededRandom {
+    pub fn new(seed: u64) -> SeededRandom {
+        return SeededRandom { seed, rng: Rng::new(seed) };
+    }
+}
+
+impl Player for SeededRandom {
+    fn name(&self) -> String {
+        return format!("random #{}", self.seed);
+    }
+
+    fn play(&mut self, _history: &[Round]) -> Move {
+        return *self.rng.choose(&MOVES);
+    }
+}
+
+/// A player's results over all the rounds of a tournament.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub player: String,
+    pub score: i32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize
+}
+
+impl Standing {
+    fn record(&mut self, own: Move, opponent: Move) {
+        self.score += get_move_score(own) + get_round_result_score(opponent, own);
+        match get_move_rule(own).get_round_result(opponent) {
+            RoundResult::Win => self.wins += 1,
+            RoundResult::Draw => self.draws += 1,
+            RoundResult::Lose => self.losses += 1
+        }
+    }
+}
+
+/// Has every player play a match of `rounds` rounds against every other one, scored as in the puzzle.
+///
+/// Standings are ordered by score, then by wins, then as the players were given.
+pub fn round_robin(players: &mut [Box<dyn Player>], rounds: usize) -> Vec<Standing> {
+    let mut standings = players.iter()
+        .map(|player| Standing { player: player.name(), score: 0, wins: 0, draws: 0, losses: 0 })
+        .collect::<Vec<Standing>>();
+
+    for i in 0..players.len() {
+        for j in i + 1..players.len() {
+            let (mut first_history, mut second_history) = (Vec::new(), Vec::new());
+            for _ in 0..rounds {
+                let first = players[i].play(&first_history);
+                let second = players[j].play(&second_history);
+                standings[i].record(first, second);
+                standings[j].record(second, first);
+                first_history.push(Round { own: first, opponent: second });
+                second_history.push(Round { own: second, opponent: first });
+            }
+        }
+    }
+
+    standings.sort_by_key(|standing| (Reverse(standing.score), Reverse(standing.wins)));
+    return standings;
+}
+
+/// The standings as a text table, one line per player.
+pub fn standings_table(standings: &[Standing]) -> String {
+    let width = standings.iter().map(|standing| standing.player.len()).max().unwrap_or(0).max("player".len());
+    let mut lines = vec![format!("rank  {:<width$}  score     W     D     L", "player", width = width)];
+    lines.extend(standings.iter().enumerate().map(|(i, standing)| format!("{:>4}  {:<width$}  {:>5} {:>5} {:>5} {:>5}",
+        i + 1, standing.player, standing.score, standing.wins, standing.draws, standing.losses, width = width)));
+    return lines.join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day02::Move, tournament::{self, Cyclic, FixedSequence, FrequencyAdaptive, Player, Round, SeededRandom, WinStayLoseShift}};
+
+    fn rounds(moves: &[(Move, Move)]) -> Vec<Round> {
+        return moves.iter().map(|(own, opponent)| Round { own: *own, opponent: *opponent }).collect();
+    }
+
+    #[test]
+    fn players_follow_their_strategies() {
+        let mut fixed = FixedSequence::new(vec![Move::Paper, Move::Scisors]);
+        assert_eq!((0..3).map(|n| fixed.play(&rounds(&vec![(Move::Rock, Move::Rock); n]))).collect::<Vec<Move>>(), vec![Move::Paper, Move::Scisors, Move::Scisors]);
+
+        let mut cyclic = Cyclic::new(vec![Move::Paper, Move::Scisors]);
+        assert_eq!(cyclic.play(&rounds(&[(Move::Rock, Move::Rock); 2])), Move::Paper);
+
+        let mut adaptive = FrequencyAdaptive;
+        assert_eq!(adaptive.play(&rounds(&[(Move::Rock, Move::Scisors), (Move::Rock, Move::Paper), (Move::Rock, Move::Scisors)])), Move::Rock);
+
+        let mut wsls = WinStayLoseShift::new(Move::Paper);
+        assert_eq!(wsls.play(&[]), Move::Paper);
+        assert_eq!(wsls.play(&rounds(&[(Move::Paper, Move::Rock)])), Move::Paper);
+        assert_eq!(wsls.play(&rounds(&[(Move::Paper, Move::Scisors)])), Move::Rock);
+
+        let moves = |seed: u64| {
+            let mut random = SeededRandom::new(seed);
+            return (0..20).map(|_| random.play(&[])).collect::<Vec<Move>>();
+        };
+        assert_eq!(moves(7), moves(7));
+        assert_ne!(moves(7), moves(8));
+    }
+
+    #[test]
+    fn ranks_players_of_round_robin() {
+        let mut players: Vec<Box<dyn Player>> = vec![
+            Box::new(Cyclic::new(vec![Move::Rock])),
+            Box::new(FrequencyAdaptive),
+            Box::new(FixedSequence::new(vec![Move::Scisors]))
+        ];
+        let standings = tournament::round_robin(&mut players, 10);
+        assert_eq!(standings.iter().map(|standing| standing.player.as_str()).collect::<Vec<&str>>(), vec!["frequency adaptive", "cyclic [Rock]", "fixed [Scisors]"]);
+        assert_eq!((standings[0].wins, standings[0].draws, standings[0].losses), (19, 1, 0));
+        // Ten wins with rock against scissors, then a draw and nine losses against the adaptive player.
+        assert_eq!(standings[1].score, 10 * (1 + 6) + (1 + 3) + 9);
+        assert!(standings.iter().all(|standing| standing.wins + standing.draws + standing.losses == 20));
+
+        let table = tournament::standings_table(&standings);
+        assert_eq!(table.lines().next(), Some("rank  player              score     W     D     L"));
+        assert_eq!(table.lines().count(), 4);
+    }
+}
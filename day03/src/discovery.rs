@@ -0,0 +1,121 @@
+use crate::day03::{ItemSet, Rucksack};
+
+/// Partitions rucksacks listed in any order into groups of `group_size` that each share exactly one item type.
+///
+/// Groups are given as indices into `rucksacks`, each group and the groups themselves in increasing order.
+/// Returns `None` if there is no such partition. The search backtracks over the possible groups of the
+/// rucksack with the fewest of them, which takes exponential time in the worst case.
+pub fn discover_groups(rucksacks: &[Rucksack], group_size: usize) -> Option<Vec<Vec<usize>>> {
+    if group_size == 0 || !rucksacks.len().is_multiple_of(group_size) {
+        return None;
+    }
+    let items = rucksacks.iter().map(|r| r.get_items()).collect::<Vec<ItemSet>>();
+    let mut search = GroupSearch { items, group_size, assigned: vec![false; rucksacks.len()], groups: Vec::new() };
+    if !search.solve() {
+        return None;
+    }
+    let mut groups = search.groups;
+    groups.sort();
+    return Some(groups);
+}
+
+struct GroupSearch {
+    items: Vec<ItemSet>,
+    group_size: usize,
+    assigned: Vec<bool>,
+    groups: Vec<Vec<usize>>
+}
+
+impl GroupSearch {
+    fn solve(&mut self) -> bool {
+        let unassigned = (0..self.items.len()).filter(|i| !self.assigned[*i]).collect::<Vec<usize>>();
+        if unassigned.is_empty() {
+            return true;
+        }
+
+        // The rucksack that fits the fewest groups is the most likely to fail, so it is placed first.
+        let mut most_constrained: Option<Vec<Vec<usize>>> = None;
+        for first in unassigned.iter() {
+            let candidates = self.candidate_groups(*first, &unassigned);
+            if most_constrained.as_ref().is_none_or(|fewest| candidates.len() < fewest.len()) {
+                let stuck = candidates.is_empty();
+                most_constrained = Some(candidates);
+                if stuck {
+                    return false;
+                }
+            }
+        }
+
+        for group in most_constrained.unwrap() {
+            group.iter().for_each(|i| self.assigned[*i] = true);
+            self.groups.push(group);
+            if self.solve() {
+                return true;
+            }
+            let group = self.groups.pop().unwrap();
+            group.iter().for_each(|i| self.assigned[*i] = false);
+        }
+        return false;
+    }
+
+    /// Groups of unassigned rucksacks containing `first` and sharing exactly one item type.
+    fn candidate_groups(&self, first: usize, unassigned: &[usize]) -> Vec<Vec<usize>> {
+        let others = unassigned.iter().copied().filter(|i| *i != first).collect::<Vec<usize>>();
+        let mut candidates = Vec::new();
+        self.extend(&mut vec![first], self.items[first], &others, &mut candidates);
+        return candidates;
+    }
+
+    fn extend(&self, group: &mut Vec<usize>, shared: ItemSet, others: &[usize], candidates: &mut Vec<Vec<usize>>) {
+        if group.len() == self.group_size {
+            if shared.len() == 1 {
+                let mut candidate = group.clone();
+                candidate.sort();
+                candidates.push(candidate);
+            }
+            return;
+        }
+        for (k, other) in others.iter().enumerate() {
+            let shared = shared.intersection(self.items[*other]);
+            if !shared.is_empty() {
+                group.push(*other);
+                self.extend(group, shared, &others[k + 1..], candidates);
+                group.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day03::{self, Rucksack}, discovery, generator};
+    use aoc_common::generate::Rng;
+
+    fn rucksacks(lines: &[&str]) -> Vec<Rucksack> {
+        return lines.iter().map(|line| Rucksack::from_items(line).unwrap()).collect();
+    }
+
+    #[test]
+    fn discovers_groups_of_shuffled_rucksacks() {
+        let found = discovery::discover_groups(&rucksacks(&["abcd", "wxyz", "aefg", "wuvt", "ahij", "wklm"]), 3);
+        assert_eq!(found, Some(vec![vec![0, 2, 4], vec![1, 3, 5]]));
+        assert_eq!(discovery::discover_groups(&rucksacks(&["abcd", "abef", "abgh"]), 3), None);
+        assert_eq!(discovery::discover_groups(&rucksacks(&["abcd", "aefg"]), 3), None);
+    }
+
+    #[test]
+    fn every_discovered_group_has_one_badge() {
+        let mut lines = generator::generate(5, &generator::Params { groups: 30, max_compartment_size: 16 }).input
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<String>>();
+        Rng::new(5).shuffle(&mut lines);
+        let rucksacks = lines.iter().map(|line| Rucksack::from_items(line).unwrap()).collect::<Vec<Rucksack>>();
+        let groups = discovery::discover_groups(&rucksacks, 3).unwrap();
+        assert_eq!(groups.iter().flatten().count(), rucksacks.len());
+        for group in groups.iter() {
+            let members = group.iter().map(|i| Rucksack::from_items(&lines[*i]).unwrap()).collect::<Vec<Rucksack>>();
+            assert!(day03::find_badge(&members).is_some(), "group {:?}", group);
+        }
+    }
+}
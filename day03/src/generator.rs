@@ -0,0 +1,75 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub groups: usize,
+    pub max_compartment_size: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { groups: 100, max_compartment_size: 16 };
+    }
+}
+
+/// Groups of three rucksacks with one item in both compartments of each rucksack
+/// and one badge carried by the whole group, both chosen up front.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    let mut shared_priorities = 0;
+    let mut badge_priorities = 0;
+
+    for _ in 0..params.groups {
+        let mut items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        badge_priorities += priority(badge);
+
+        for pool in items[1..].chunks(17) {
+            let mut available = pool.to_vec();
+            available.push(badge);
+            rng.shuffle(&mut available);
+
+            let shared = available[0];
+            shared_priorities += priority(shared);
+            let (left_only, right_only) = available[1..].split_at(rng.between(1, available.len() as i64 - 2) as usize);
+
+            let size = rng.between(2, params.max_compartment_size) as usize;
+            let mut compartments = [left_only, right_only].map(|only| {
+                let mut compartment = vec![shared];
+                compartment.extend((1..size).map(|_| *rng.choose(only)));
+                return compartment;
+            });
+            if let Some(side) = [left_only, right_only].iter().position(|only| only.contains(&badge)) {
+                compartments[side][1] = badge;
+            }
+            for compartment in compartments.iter_mut() {
+                rng.shuffle(compartment);
+            }
+            lines.push(compartments.concat().iter().collect::<String>());
+        }
+    }
+
+    return GeneratedInput {
+        input: lines.join("\n"),
+        part1: Some(shared_priorities.to_string()),
+        part2: Some(badge_priorities.to_string())
+    };
+}
+
+fn priority(item: char) -> u32 {
+    return ('a'..='z').chain('A'..='Z').position(|c| c == item).unwrap() as u32 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day03::Day03, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day03::default()), Ok(()), "seed {}", seed);
+        }
+    }
+}
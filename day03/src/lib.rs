@@ -0,0 +1,416 @@
+pub mod discovery;
+pub mod generator;
+pub mod priorities;
+pub mod reorganisation;
+
+pub mod day03 {
+    use std::io::BufRead;
+    use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+    use crate::priorities::{PriorityTable, MAX_PRIORITY};
+    
+    pub fn sum_priorities_of_items_appearing_in_both_compartments(input: &mut dyn BufRead) -> Result<u32, ParseError> {
+        let day = Day03::default();
+        return day.part1(&day.parse(input)?);
+    }
+
+    fn sum_priorities_of_items_appearing_in_both_compartments_of_a_rucksack(r: &Rucksack) -> u32 {
+        return r.get_shared_items().sum_priorities();
+    }
+
+    pub fn sum_priorities_of_groups_badges(input: &mut dyn BufRead) -> Result<u32, ParseError> {
+        let day = Day03::default();
+        return day.part2(&day.parse(input)?);
+    }
+
+    /// Groups consecutive rucksacks by `group_size`, which must be at least 1, and reads items with `priorities`.
+    pub struct Day03 {
+        pub group_size: usize,
+        pub priorities: PriorityTable
+    }
+
+    impl Default for Day03 {
+        fn default() -> Day03 {
+            return Day03 { group_size: 3, priorities: PriorityTable::alphabetic() };
+        }
+    }
+
+    impl Solution for Day03 {
+        type Parsed = Vec<Rucksack>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Rucksack>, ParseError> {
+            return parse_input(input, &self.priorities);
+        }
+
+        fn part1(&self, rucksacks: &Vec<Rucksack>) -> Result<u32, ParseError> {
+            return Ok(rucksacks.iter().map(|r| sum_priorities_of_items_appearing_in_both_compartments_of_a_rucksack(r)).sum());
+        }
+
+        /// Fails unless the rucksacks fill whole groups that each share exactly one item type.
+        fn part2(&self, rucksacks: &Vec<Rucksack>) -> Result<u32, ParseError> {
+            return Ok(group_rucksacks(rucksacks, self.group_size)?.iter().map(|group| group.get_badge()).sum());
+        }
+    }
+
+    /// Rucksacks of elves sharing exactly one item type, their badge.
+    pub struct Group<'a> {
+        rucksacks: &'a [Rucksack],
+        badge: u32
+    }
+
+    impl<'a> Group<'a> {
+        pub fn get_rucksacks(&self) -> &'a [Rucksack] {
+            return self.rucksacks;
+        }
+
+        /// Priority of the badge.
+        pub fn get_badge(&self) -> u32 {
+            return self.badge;
+        }
+
+        /// Item types carried by any rucksack of the group.
+        pub fn get_all_items(&self) -> ItemSet {
+            return ItemSet::union_of(self.rucksacks.iter().map(|r| r.get_items()));
+        }
+    }
+
+    /// Splits consecutive rucksacks, one per line, into groups of `group_size`.
+    ///
+    /// Fails unless `group_size` is at least 1, the rucksacks fill whole groups and every group shares exactly one item type.
+    pub fn group_rucksacks(rucksacks: &[Rucksack], group_size: usize) -> Result<Vec<Group<'_>>, ParseError> {
+        if group_size == 0 {
+            return Err(ParseError::new(1, 1, "groups of at least one rucksack"));
+        }
+        let count = rucksacks.len();
+        if !count.is_multiple_of(group_size) {
+            let missing = group_size - count % group_size;
+            return Err(ParseError::new(count + 1, 1, format!("{} more rucksacks to complete the last group of {}", missing, group_size)));
+        }
+
+        let mut groups = Vec::new();
+        for (i, group) in rucksacks.chunks(group_size).enumerate() {
+            let first = i * group_size + 1;
+            let Some(badge) = find_badge(group) else {
+                let shared = find_shared_items(group).len();
+                let expected = format!("rucksacks on lines {} to {} sharing exactly one item type, not {}", first, first + group_size - 1, shared);
+                return Err(ParseError::new(first, 1, expected));
+            };
+            groups.push(Group { rucksacks: group, badge });
+        }
+        return Ok(groups);
+    }
+
+    /// Item types carried by every rucksack of a group.
+    pub fn find_shared_items(group: &[Rucksack]) -> ItemSet {
+        return ItemSet::intersection_of(group.iter().map(|r| r.get_items()));
+    }
+
+    /// Priority of the badge of a group, if its rucksacks share exactly one item type.
+    pub fn find_badge(group: &[Rucksack]) -> Option<u32> {
+        let shared = find_shared_items(group);
+        if shared.len() != 1 {
+            return None;
+        }
+        return Some(shared.sum_priorities());
+    }
+
+    fn parse_input(input: &mut dyn BufRead, priorities: &PriorityTable) -> Result<Vec<Rucksack>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines).iter().map(|line| Rucksack::parse(line, priorities)).collect();
+    }
+
+    /// Priority of a letter in the puzzle, `None` for anything else.
+    pub fn get_item_priority(item: char) -> Option<u32> {
+        if item.is_ascii_lowercase() {
+            return Some(item as u32 - 'a' as u32 + 1);
+        }
+        else if item.is_ascii_uppercase() {
+            return Some(item as u32 - 'A' as u32 + 27);
+        }
+        else {
+            return None;
+        }
+    }
+
+    /// Set of item types, one bit per priority.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct ItemSet {
+        bits: u64
+    }
+
+    impl ItemSet {
+        pub fn new() -> ItemSet {
+            return ItemSet { bits: 0 };
+        }
+
+        /// Fails on priorities outside 1 to [`MAX_PRIORITY`].
+        pub fn from_priorities(priorities: impl IntoIterator<Item = u32>) -> Result<ItemSet, String> {
+            let mut set = ItemSet::new();
+            for priority in priorities {
+                set.insert(priority)?;
+            }
+            return Ok(set);
+        }
+
+        /// Union of all the sets, empty if there are none.
+        pub fn union_of(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+            return sets.into_iter().fold(ItemSet::new(), |union, set| union.union(set));
+        }
+
+        /// Intersection of all the sets, empty if there are none.
+        pub fn intersection_of(sets: impl IntoIterator<Item = ItemSet>) -> ItemSet {
+            return sets.into_iter().reduce(|intersection, set| intersection.intersection(set)).unwrap_or_default();
+        }
+
+        /// Fails on a priority outside 1 to [`MAX_PRIORITY`], which has no bit in the set.
+        pub fn insert(&mut self, priority: u32) -> Result<(), String> {
+            if !(1..=MAX_PRIORITY).contains(&priority) {
+                return Err(format!("priority must be between 1 and {}, not {}", MAX_PRIORITY, priority));
+            }
+            self.bits |= 1 << priority;
+            return Ok(());
+        }
+
+        pub fn contains(&self, priority: u32) -> bool {
+            return self.bits & 1u64.checked_shl(priority).unwrap_or(0) != 0;
+        }
+
+        pub fn union(self, other: ItemSet) -> ItemSet {
+            return ItemSet { bits: self.bits | other.bits };
+        }
+
+        pub fn intersection(self, other: ItemSet) -> ItemSet {
+            return ItemSet { bits: self.bits & other.bits };
+        }
+
+        /// Item types of this set missing from `other`.
+        pub fn difference(self, other: ItemSet) -> ItemSet {
+            return ItemSet { bits: self.bits & !other.bits };
+        }
+
+        pub fn len(&self) -> usize {
+            return self.bits.count_ones() as usize;
+        }
+
+        pub fn is_empty(&self) -> bool {
+            return self.bits == 0;
+        }
+
+        /// Priorities of the item types, in increasing order.
+        pub fn get_priorities(&self) -> Vec<u32> {
+            let mut bits = self.bits;
+            let mut priorities = Vec::with_capacity(self.len());
+            while bits != 0 {
+                priorities.push(bits.trailing_zeros());
+                bits &= bits - 1;
+            }
+            return priorities;
+        }
+
+        pub fn sum_priorities(&self) -> u32 {
+            return self.get_priorities().iter().sum();
+        }
+    }
+
+    pub struct Rucksack {
+        c1: Compartment,
+        c2: Compartment
+    }
+
+    impl Rucksack {
+        pub fn new(c1: Compartment, c2: Compartment) -> Rucksack {
+            return Rucksack { c1, c2 };
+        }
+
+        /// Splits a line of items in half, one half per compartment.
+        ///
+        /// Fails on items missing from `priorities` and on lines with an odd number of items.
+        pub fn parse(line: &Line, priorities: &PriorityTable) -> Result<Rucksack, ParseError> {
+            let mut items = line.text.chars()
+                .enumerate()
+                .map(|(i, item)| priorities.get_priority(item).ok_or_else(|| line.error_at(i + 1, format!("an item of the priority table, not {}", item))))
+                .collect::<Result<Vec<u32>, ParseError>>()?;
+            if items.len() % 2 != 0 {
+                return Err(line.error_at(line.end_column(), format!("an even number of items, not {}", items.len())));
+            }
+            let second = items.split_off(items.len() / 2);
+            let compartment = |items: Vec<u32>| Compartment::new(items).expect("priorities of a table are in range");
+            return Ok(Rucksack::new(compartment(items), compartment(second)));
+        }
+
+        /// Reads a line of the puzzle, with letters as items.
+        pub fn from_items(items: &str) -> Result<Rucksack, ParseError> {
+            return Rucksack::parse(&Line::new(1, items), &PriorityTable::alphabetic());
+        }
+
+        pub fn get_compartments(&self) -> (&Compartment, &Compartment) {
+            return (&self.c1, &self.c2);
+        }
+
+        /// Item types packed in both compartments.
+        pub fn get_shared_items(&self) -> ItemSet {
+            return self.c1.items.intersection(self.c2.items);
+        }
+
+        /// Priorities of the item types packed in both compartments, in increasing order.
+        pub fn find_items_appearing_in_both_compartments(&self) -> Vec<u32> {
+            return self.get_shared_items().get_priorities();
+        }
+
+        /// Item types packed in either compartment.
+        pub fn get_items(&self) -> ItemSet {
+            return self.c1.items.union(self.c2.items);
+        }
+    }
+
+    pub struct Compartment {
+        items: ItemSet,
+        len: usize
+    }
+
+    impl Compartment {
+        /// A compartment packed with items of the given priorities, failing unless each is between 1 and [`MAX_PRIORITY`].
+        pub fn new(items: Vec<u32>) -> Result<Compartment, String> {
+            return Ok(Compartment { len: items.len(), items: ItemSet::from_priorities(items.iter().copied())? });
+        }
+
+        /// Item types in the compartment.
+        pub fn get_items(&self) -> ItemSet {
+            return self.items;
+        }
+
+        /// Number of items packed, counting every item of the same type.
+        pub fn len(&self) -> usize {
+            return self.len;
+        }
+
+        pub fn is_empty(&self) -> bool {
+            return self.len == 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day03, priorities::PriorityTable};
+    use aoc_common::{parse::ParseError, solution::{Solution, Solver}};
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn items_appearing_in_both_compartments() {
+        assert_eq!(day03::sum_priorities_of_items_appearing_in_both_compartments(&mut "vJrwpWtwJgWrhcsFMMfFFhFp".as_bytes()).unwrap(), 16);
+    }
+
+    #[test]
+    fn sum_uniqe_items() {
+        assert_eq!(day03::sum_priorities_of_items_appearing_in_both_compartments(&mut "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".as_bytes()).unwrap(), 38);
+    }
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day03::sum_priorities_of_items_appearing_in_both_compartments(&mut f).unwrap(), 157);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day03::sum_priorities_of_items_appearing_in_both_compartments(&mut f).unwrap(), 7997);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day03::sum_priorities_of_groups_badges(&mut f).unwrap(), 70);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day03::sum_priorities_of_groups_badges(&mut f).unwrap(), 2545);
+    }
+
+    #[test]
+    fn groups_rucksacks_by_configured_size() {
+        let day = day03::Day03 { group_size: 2, ..Default::default() };
+        let rucksacks = day.parse(&mut "abAb\nAdeA\nxyCz\nzwvq".as_bytes()).unwrap();
+        let groups = day03::group_rucksacks(&rucksacks, 2).unwrap();
+        assert_eq!(groups.iter().map(|group| group.get_badge()).collect::<Vec<u32>>(), vec![day03::get_item_priority('A').unwrap(), day03::get_item_priority('z').unwrap()]);
+        assert_eq!(day.part1(&rucksacks), Ok(day03::get_item_priority('A').unwrap() + day03::get_item_priority('b').unwrap()));
+        assert_eq!(day.part2(&rucksacks), Ok(day03::get_item_priority('A').unwrap() + day03::get_item_priority('z').unwrap()));
+
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        let day = day03::Day03 { group_size: 1, ..Default::default() };
+        assert_eq!(day.solve_part(&mut f, 2).unwrap().err().map(|e| e.expected), Some("rucksacks on lines 1 to 1 sharing exactly one item type, not 13".to_string()));
+        assert_eq!(day03::group_rucksacks(&rucksacks, 0).err(), Some(ParseError::new(1, 1, "groups of at least one rucksack")));
+    }
+
+    #[test]
+    fn answers_part1_without_complete_groups() {
+        let day = day03::Day03::default();
+        assert_eq!(day.solve_part(&mut "vJrwpWtwJgWrhcsFMMfFFhFp".as_bytes(), 1), Some(Ok("16".to_string())));
+        assert_eq!(day.solve_part(&mut "vJrwpWtwJgWrhcsFMMfFFhFp".as_bytes(), 2), Some(Err(ParseError::new(2, 1, "2 more rucksacks to complete the last group of 3"))));
+    }
+
+    #[test]
+    fn rejects_groups_without_exactly_one_badge() {
+        let day = day03::Day03::default();
+        let badges = |input: &str| day.parse(&mut input.as_bytes()).and_then(|rucksacks| day.part2(&rucksacks));
+        assert_eq!(badges("abcd\nabef\nabgh").err(), Some(ParseError::new(1, 1, "rucksacks on lines 1 to 3 sharing exactly one item type, not 2")));
+        assert_eq!(badges("abcd\naxyz\nabwz\nabcd\nefgh\nijkl").err(), Some(ParseError::new(4, 1, "rucksacks on lines 4 to 6 sharing exactly one item type, not 0")));
+        assert_eq!(badges("abcd\nabef\nabgh\nabcd").err(), Some(ParseError::new(5, 1, "2 more rucksacks to complete the last group of 3")));
+        assert_eq!(day03::find_badge(&[day03::Rucksack::from_items("abcd").unwrap(), day03::Rucksack::from_items("dcef").unwrap()]), None);
+    }
+
+    #[test]
+    fn combines_item_sets() {
+        let (c1, c2) = (day03::Compartment::new(vec![1, 2, 3, 1, 2]).unwrap(), day03::Compartment::new(vec![2, 3, 4, 52]).unwrap());
+        let (p1, p2) = (c1.get_items(), c2.get_items());
+        assert_eq!(p1.union(p2).get_priorities(), vec![1, 2, 3, 4, 52]);
+        assert_eq!(p1.intersection(p2).get_priorities(), vec![2, 3]);
+        assert_eq!(p1.difference(p2).get_priorities(), vec![1]);
+        assert_eq!(p2.difference(p1).sum_priorities(), 4 + 52);
+        assert!(p1.contains(1) && !p1.contains(52));
+        assert_eq!(day03::ItemSet::intersection_of([]), day03::ItemSet::new());
+
+        let rucksacks = [day03::Rucksack::from_items("abAb").unwrap(), day03::Rucksack::from_items("AdeA").unwrap()];
+        let groups = day03::group_rucksacks(&rucksacks, 2).unwrap();
+        assert_eq!(groups[0].get_all_items(), day03::ItemSet::from_priorities([1, 2, 27, 4, 5]).unwrap());
+    }
+
+    #[test]
+    fn rejects_priorities_without_a_bit() {
+        assert_eq!(day03::Compartment::new(vec![1, 64]).err(), Some("priority must be between 1 and 63, not 64".to_string()));
+        assert_eq!(day03::ItemSet::from_priorities([0]), Err("priority must be between 1 and 63, not 0".to_string()));
+        assert!(day03::Compartment::new(vec![63]).unwrap().get_items().contains(63));
+        assert!(!day03::ItemSet::new().contains(200));
+    }
+
+    #[test]
+    fn rejects_unknown_items_and_odd_rucksacks() {
+        let day = day03::Day03::default();
+        assert_eq!(day.parse(&mut "abca\nab?b".as_bytes()).err(), Some(ParseError::new(2, 3, "an item of the priority table, not ?")));
+        assert_eq!(day.parse(&mut "abcab".as_bytes()).err(), Some(ParseError::new(1, 6, "an even number of items, not 5")));
+    }
+
+    #[test]
+    fn reads_items_with_custom_priorities() {
+        let day = day03::Day03 { group_size: 1, priorities: PriorityTable::from_symbols("🍎🍌🍒🥝").unwrap() };
+        let rucksacks = day.parse(&mut "🍌🍌🍌🍌".as_bytes()).unwrap();
+        assert_eq!((day.part1(&rucksacks), day.part2(&rucksacks)), (Ok(2), Ok(2)));
+
+        let mut f = BufReader::new(File::open("./fruit.priorities").unwrap());
+        let day = day03::Day03 { group_size: 2, priorities: PriorityTable::read(&mut f).unwrap() };
+        let rucksacks = day.parse(&mut "🍎🍌🍒🍎\n🍒🥝🥝🍋".as_bytes()).unwrap();
+        assert_eq!((day.part1(&rucksacks), day.part2(&rucksacks)), (Ok(10 + 40), Ok(30)));
+    }
+
+    #[test]
+    fn inspects_rucksack_through_library_api() {
+        let rucksack = day03::Rucksack::from_items("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        assert_eq!(rucksack.get_compartments().0.len(), 12);
+        assert_eq!(rucksack.get_compartments().0.get_items().len(), 8);
+        assert_eq!(rucksack.find_items_appearing_in_both_compartments(), vec![day03::get_item_priority('p').unwrap()]);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{collections::HashMap, io::BufRead};
+use aoc_common::{input::read_input, parse::{number_lines, ParseError}};
+use crate::day03::get_item_priority;
+
+/// Highest priority an item can have, so that item sets fit in 64 bits.
+pub const MAX_PRIORITY: u32 = 63;
+
+/// Priorities of the item types that can be packed in rucksacks.
+///
+/// The priority of an item identifies its type, so no two items share one, and priorities are between 1 and [`MAX_PRIORITY`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PriorityTable {
+    ascii: [u32; 128],
+    others: HashMap<char, u32>
+}
+
+impl Default for PriorityTable {
+    fn default() -> PriorityTable {
+        return PriorityTable::alphabetic();
+    }
+}
+
+impl PriorityTable {
+    /// Checks that items and priorities are all distinct and priorities are in range.
+    pub fn new(priorities: impl IntoIterator<Item = (char, u32)>) -> Result<PriorityTable, String> {
+        let mut table = PriorityTable { ascii: [0; 128], others: HashMap::new() };
+        let mut items_by_priority = HashMap::new();
+        for (item, priority) in priorities {
+            if !(1..=MAX_PRIORITY).contains(&priority) {
+                return Err(format!("priority of {} must be between 1 and {}, not {}", item, MAX_PRIORITY, priority));
+            }
+            if table.get_priority(item).is_some() {
+                return Err(format!("item {} is listed twice", item));
+            }
+            if let Some(other) = items_by_priority.insert(priority, item) {
+                return Err(format!("items {} and {} have the same priority {}", other, item, priority));
+            }
+            match table.ascii.get_mut(item as usize) {
+                Some(slot) => *slot = priority,
+                None => {
+                    table.others.insert(item, priority);
+                }
+            }
+        }
+        return Ok(table);
+    }
+
+    /// The puzzle's priorities: `a` to `z` are 1 to 26 and `A` to `Z` 27 to 52.
+    pub fn alphabetic() -> PriorityTable {
+        let letters = ('a'..='z').chain('A'..='Z');
+        return PriorityTable::new(letters.map(|item| (item, get_item_priority(item).unwrap()))).unwrap();
+    }
+
+    /// Any symbols, such as emoji, with priorities 1, 2, 3... in the order they are given.
+    pub fn from_symbols(symbols: &str) -> Result<PriorityTable, String> {
+        return PriorityTable::new(symbols.chars().zip(1..));
+    }
+
+    /// Reads a mapping file with one item per line, as the item symbol and its priority separated by a space.
+    pub fn read(input: &mut dyn BufRead) -> Result<PriorityTable, ParseError> {
+        let lines = read_input(input)?;
+        let mut entries: Vec<(char, u32)> = Vec::new();
+        for line in number_lines(&lines) {
+            let (symbol, number) = line.text.split_once(' ').ok_or_else(|| line.error_at(line.end_column(), "a space and a priority"))?;
+            let mut chars = symbol.chars();
+            let item = match (chars.next(), chars.next()) {
+                (Some(item), None) => item,
+                _ => return Err(line.error_on(symbol, "a single item symbol"))
+            };
+            let priority = line.parse::<u32>(number, "a priority")
+                .ok()
+                .filter(|priority| (1..=MAX_PRIORITY).contains(priority))
+                .ok_or_else(|| line.error_on(number, format!("a priority between 1 and {}", MAX_PRIORITY)))?;
+            if entries.iter().any(|(other, _)| *other == item) {
+                return Err(line.error_on(symbol, "an item symbol not listed before"));
+            }
+            if let Some((other, _)) = entries.iter().find(|(_, other)| *other == priority) {
+                return Err(line.error_on(number, format!("a priority not already given to {}", other)));
+            }
+            entries.push((item, priority));
+        }
+        return Ok(PriorityTable::new(entries).unwrap());
+    }
+
+    pub fn get_priority(&self, item: char) -> Option<u32> {
+        let priority = match self.ascii.get(item as usize) {
+            Some(priority) => *priority,
+            None => *self.others.get(&item)?
+        };
+        return Some(priority).filter(|priority| *priority != 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::priorities::PriorityTable;
+    use aoc_common::parse::ParseError;
+
+    #[test]
+    fn builds_tables() {
+        let alphabetic = PriorityTable::alphabetic();
+        assert_eq!((alphabetic.get_priority('a'), alphabetic.get_priority('Z'), alphabetic.get_priority('?')), (Some(1), Some(52), None));
+
+        let fruit = PriorityTable::from_symbols("🍎🍌🍒").unwrap();
+        assert_eq!((fruit.get_priority('🍒'), fruit.get_priority('a')), (Some(3), None));
+
+        assert_eq!(PriorityTable::from_symbols("aba"), Err("item a is listed twice".to_string()));
+        assert_eq!(PriorityTable::new([('a', 1), ('é', 1)]), Err("items a and é have the same priority 1".to_string()));
+        assert_eq!(PriorityTable::new([('a', 64)]), Err("priority of a must be between 1 and 63, not 64".to_string()));
+    }
+
+    #[test]
+    fn reads_mapping_files() {
+        let table = PriorityTable::read(&mut "🍎 10\n🍌 2\nx 63".as_bytes()).unwrap();
+        assert_eq!(table.get_priority('🍎'), Some(10));
+        assert_eq!(table.get_priority('x'), Some(63));
+
+        assert_eq!(PriorityTable::read(&mut "ab 1".as_bytes()), Err(ParseError::new(1, 1, "a single item symbol")));
+        assert_eq!(PriorityTable::read(&mut "a 1\nb".as_bytes()), Err(ParseError::new(2, 2, "a space and a priority")));
+        assert_eq!(PriorityTable::read(&mut "a 0".as_bytes()), Err(ParseError::new(1, 3, "a priority between 1 and 63")));
+        assert_eq!(PriorityTable::read(&mut "a 1\na 2".as_bytes()), Err(ParseError::new(2, 1, "an item symbol not listed before")));
+        assert_eq!(PriorityTable::read(&mut "🍎 1\n🍌 1".as_bytes()), Err(ParseError::new(2, 3, "a priority not already given to 🍎")));
+    }
+}
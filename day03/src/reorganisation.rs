@@ -0,0 +1,176 @@
+use std::{fmt, io::BufRead};
+use aoc_common::{input::read_input, parse::{number_lines, ParseError}};
+use crate::{day03::Rucksack, priorities::PriorityTable};
+
+/// Exchange of two items between the compartments of a rucksack, by their 0-based positions in each compartment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Swap {
+    pub first: usize,
+    pub second: usize
+}
+
+/// How to rearrange a rucksack so that no item type is in both compartments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reorganisation {
+    /// As few swaps as possible, applied in any order.
+    pub swaps: Vec<Swap>,
+    /// The items once swapped, as a line of the puzzle.
+    pub items: String
+}
+
+/// Why a rucksack cannot be reorganised.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Obstacle {
+    /// There are more items of one type than a compartment holds.
+    TooManyOfOneItem { item: char, count: usize, capacity: usize },
+    /// No item types add up to exactly the size of a compartment.
+    NoExactSplit { capacity: usize }
+}
+
+impl fmt::Display for Obstacle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Obstacle::TooManyOfOneItem { item, count, capacity } => write!(f, "{} items {} do not fit in a compartment of {}", count, item, capacity),
+            Obstacle::NoExactSplit { capacity } => write!(f, "no item types add up to exactly {} items, the size of a compartment", capacity)
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackPlan {
+    pub line: usize,
+    pub outcome: Result<Reorganisation, Obstacle>
+}
+
+/// Plans the reorganisation of every rucksack of a puzzle input, read with `priorities`.
+pub fn plan_reorganisation(input: &mut dyn BufRead, priorities: &PriorityTable) -> Result<Vec<RucksackPlan>, ParseError> {
+    let lines = read_input(input)?;
+    return number_lines(&lines).iter()
+        .map(|line| {
+            Rucksack::parse(line, priorities)?;
+            return Ok(RucksackPlan { line: line.number, outcome: plan_rucksack(line.text) });
+        })
+        .collect();
+}
+
+/// Finds the fewest swaps leaving every item type in a single compartment of a rucksack with an even number of items.
+///
+/// Each type goes entirely to one compartment, and the types of the first compartment must fill it exactly. Among
+/// those choices, the one keeping the most items of the first compartment in place needs the fewest swaps, as every
+/// swap brings back one misplaced item from each side. This is a knapsack over the item types, solved exactly.
+pub fn plan_rucksack(items: &str) -> Result<Reorganisation, Obstacle> {
+    let items = items.chars().collect::<Vec<char>>();
+    let capacity = items.len() / 2;
+    let (first, second) = items.split_at(capacity);
+
+    // Item types in order of appearance, with their counts in the first compartment and in the whole rucksack.
+    let mut types: Vec<(char, usize, usize)> = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        let index = match types.iter().position(|(other, _, _)| other == item) {
+            Some(index) => index,
+            None => {
+                types.push((*item, 0, 0));
+                types.len() - 1
+            }
+        };
+        types[index].1 += usize::from(i < capacity);
+        types[index].2 += 1;
+    }
+    if let Some((item, _, count)) = types.iter().find(|(_, _, count)| *count > capacity) {
+        return Err(Obstacle::TooManyOfOneItem { item: *item, count: *count, capacity });
+    }
+
+    // kept[w] is the most items left in place in the first compartment by types counting w items in total.
+    let mut kept: Vec<Option<usize>> = vec![None; capacity + 1];
+    kept[0] = Some(0);
+    let mut taken = vec![vec![false; capacity + 1]; types.len()];
+    for (t, (_, in_first, count)) in types.iter().enumerate() {
+        for w in (*count..=capacity).rev() {
+            if let Some(previous) = kept[w - count] {
+                if kept[w].is_none_or(|best| previous + in_first > best) {
+                    kept[w] = Some(previous + in_first);
+                    taken[t][w] = true;
+                }
+            }
+        }
+    }
+    if kept[capacity].is_none() {
+        return Err(Obstacle::NoExactSplit { capacity });
+    }
+
+    let mut in_first_compartment = vec![false; types.len()];
+    let mut w = capacity;
+    for t in (0..types.len()).rev() {
+        if taken[t][w] {
+            in_first_compartment[t] = true;
+            w -= types[t].2;
+        }
+    }
+    let goes_first = |item: &char| in_first_compartment[types.iter().position(|(other, _, _)| other == item).unwrap()];
+
+    let misplaced_in_first = first.iter().enumerate().filter(|(_, item)| !goes_first(item)).map(|(i, _)| i);
+    let misplaced_in_second = second.iter().enumerate().filter(|(_, item)| goes_first(item)).map(|(i, _)| i);
+    let swaps = misplaced_in_first.zip(misplaced_in_second)
+        .map(|(first, second)| Swap { first, second })
+        .collect::<Vec<Swap>>();
+
+    let (mut first, mut second) = (first.to_vec(), second.to_vec());
+    for swap in swaps.iter() {
+        std::mem::swap(&mut first[swap.first], &mut second[swap.second]);
+    }
+    return Ok(Reorganisation { swaps, items: first.iter().chain(second.iter()).collect() });
+}
+
+/// The reorganised rucksacks in the puzzle's format, or `None` if any of them cannot be reorganised.
+pub fn to_puzzle_input(plans: &[RucksackPlan]) -> Option<String> {
+    return plans.iter()
+        .map(|plan| plan.outcome.as_ref().ok().map(|reorganisation| reorganisation.items.as_str()))
+        .collect::<Option<Vec<&str>>>()
+        .map(|lines| lines.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day03, priorities::PriorityTable, reorganisation::{self, Obstacle, Swap}};
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn plans_fewest_swaps() {
+        let plan = reorganisation::plan_rucksack("abab").unwrap();
+        assert_eq!((plan.swaps, plan.items.as_str()), (vec![Swap { first: 1, second: 0 }], "aabb"));
+
+        // Only `c` and `s`, alone in the second compartment, can take the place of the first `p`.
+        let plan = reorganisation::plan_rucksack("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+        assert_eq!(plan.swaps.len(), 1);
+        assert!(day03::Rucksack::from_items(&plan.items).unwrap().find_items_appearing_in_both_compartments().is_empty());
+
+        assert_eq!(reorganisation::plan_rucksack("abcabd").unwrap().swaps.len(), 1);
+        assert_eq!(reorganisation::plan_rucksack("aabbbbaa").unwrap().swaps.len(), 2);
+        assert_eq!(reorganisation::plan_rucksack("abcd").unwrap().swaps, vec![]);
+    }
+
+    #[test]
+    fn explains_impossible_reorganisations() {
+        assert_eq!(reorganisation::plan_rucksack("aaab"), Err(Obstacle::TooManyOfOneItem { item: 'a', count: 3, capacity: 2 }));
+        let obstacle = reorganisation::plan_rucksack("aaabbbcc").unwrap_err();
+        assert_eq!(obstacle, Obstacle::NoExactSplit { capacity: 4 });
+        assert_eq!(obstacle.to_string(), "no item types add up to exactly 4 items, the size of a compartment");
+    }
+
+    #[test]
+    fn reorganises_puzzle_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let plans = reorganisation::plan_reorganisation(&mut f, &PriorityTable::alphabetic()).unwrap();
+        let swaps = plans.iter().map(|plan| plan.outcome.as_ref().map(|reorganisation| reorganisation.swaps.len())).collect::<Vec<Result<usize, &Obstacle>>>();
+        assert_eq!(swaps, vec![Ok(1), Ok(2), Ok(1), Ok(2), Ok(2), Ok(1)]);
+        let reorganised = reorganisation::to_puzzle_input(&plans).unwrap();
+        assert_eq!(day03::sum_priorities_of_items_appearing_in_both_compartments(&mut reorganised.as_bytes()), Ok(0));
+
+        assert_eq!(reorganisation::plan_reorganisation(&mut "abab\nabc".as_bytes(), &PriorityTable::alphabetic()),
+            Err(ParseError::new(2, 4, "an even number of items, not 3")));
+        let plans = reorganisation::plan_reorganisation(&mut "abab\naaab".as_bytes(), &PriorityTable::alphabetic()).unwrap();
+        assert_eq!(plans[1].line, 2);
+        assert_eq!(reorganisation::to_puzzle_input(&plans), None);
+    }
+}
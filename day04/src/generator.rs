@@ -0,0 +1,56 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub pairs: usize,
+    pub max_section: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { pairs: 1000, max_section: 99 };
+    }
+}
+
+/// Random pairs of section ranges, compared as bit masks of sections.
+///
+/// `max_section` must be below 128.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let mut range = || {
+        let start = rng.between(1, params.max_section);
+        return (start, rng.between(start, params.max_section));
+    };
+    let pairs = (0..params.pairs)
+        .map(|_| (range(), range()))
+        .collect::<Vec<((i64, i64), (i64, i64))>>();
+
+    let input = pairs.iter()
+        .map(|((s1, e1), (s2, e2))| format!("{}-{},{}-{}", s1, e1, s2, e2))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let masks = pairs.iter()
+        .map(|(elf1, elf2)| (mask(*elf1), mask(*elf2)))
+        .collect::<Vec<(u128, u128)>>();
+    let contained = masks.iter().filter(|(m1, m2)| m1 & m2 == *m1 || m1 & m2 == *m2).count();
+    let overlapping = masks.iter().filter(|(m1, m2)| m1 & m2 != 0).count();
+
+    return GeneratedInput { input, part1: Some(contained.to_string()), part2: Some(overlapping.to_string()) };
+}
+
+fn mask((start, end): (i64, i64)) -> u128 {
+    return (start..=end).fold(0, |mask, section| mask | 1 << section);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day04::Day04, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day04), Ok(()), "seed {}", seed);
+        }
+    }
+}
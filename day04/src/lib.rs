@@ -0,0 +1,130 @@
+pub mod generator;
+
+pub mod day04 {
+    use std::io::BufRead;
+    use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+
+    pub fn count_overlapping_pairs(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day04.part1(&Day04.parse(input)?);
+    }
+
+    pub fn count_partially_overlapping_pairs(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day04.part2(&Day04.parse(input)?);
+    }
+
+    pub struct Day04;
+
+    impl Solution for Day04 {
+        type Parsed = Vec<Pair>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Pair>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, pairs: &Vec<Pair>) -> Result<usize, ParseError> {
+            return Ok(pairs.iter().filter(|pair| pair.is_overlapping()).count());
+        }
+
+        fn part2(&self, pairs: &Vec<Pair>) -> Result<usize, ParseError> {
+            return Ok(pairs.iter().filter(|pair| pair.is_partially_overlapping()).count());
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Pair>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines).iter().map(|line| parse_pair(line)).collect();
+    }
+
+
+    fn parse_pair(line: &Line) -> Result<Pair, ParseError> {
+        let (elf1, elf2) = line.text.split_once(',')
+            .ok_or_else(|| line.error_at(line.end_column(), "`,` between the two ranges"))?;
+        
+        return Ok(Pair::new(parse_range(line, elf1)?, parse_range(line, elf2)?));
+    }
+
+    fn parse_range(line: &Line, range: &str) -> Result<(u32, u32), ParseError> {
+        let (start, end) = range.split_once('-')
+            .ok_or_else(|| line.error_on(range, "a range like `2-4`"))?;
+        return Ok((line.parse::<u32>(start, "a section number")?, line.parse::<u32>(end, "a section number")?));
+    }
+
+    pub struct Pair {
+        elf1: (u32, u32),
+        elf2: (u32, u32)
+    }
+
+    impl Pair {
+        /// A pair of inclusive section ranges, one per elf.
+        pub fn new(elf1: (u32, u32), elf2: (u32, u32)) -> Pair {
+            return Pair { elf1, elf2 };
+        }
+
+        pub fn get_sections(&self) -> ((u32, u32), (u32, u32)) {
+            return (self.elf1, self.elf2);
+        }
+
+        /// Whether one range fully contains the other.
+        pub fn is_overlapping(&self) -> bool {
+            return (self.elf1.0 >= self.elf2.0 && self.elf1.1 <= self.elf2.1)
+                || (self.elf2.0 >= self.elf1.0 && self.elf2.1 <= self.elf1.1);
+        }
+
+        pub fn is_partially_overlapping(&self) -> bool {
+            return (self.elf1.0 >= self.elf2.0 && self.elf1.0 <= self.elf2.1)
+                || (self.elf1.1 >= self.elf2.0 && self.elf1.1 <= self.elf2.1)
+                || (self.elf2.0 >= self.elf1.0 && self.elf2.0 <= self.elf1.1)
+                || (self.elf2.1 >= self.elf1.0 && self.elf2.1 <= self.elf1.1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day04;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day04::count_overlapping_pairs(&mut f).unwrap(), 2);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day04::count_overlapping_pairs(&mut f).unwrap(), 550);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day04::count_partially_overlapping_pairs(&mut f).unwrap(), 4);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day04::count_partially_overlapping_pairs(&mut f).unwrap(), 931);
+    }
+
+    #[test]
+    fn reports_malformed_range() {
+        let error = day04::count_overlapping_pairs(&mut "2-4,6-8\n2-3,4_5".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 5, "a range like `2-4`"));
+
+        let error = day04::count_overlapping_pairs(&mut "2-4,6-x".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 7, "a section number"));
+    }
+
+    #[test]
+    fn checks_overlaps_through_library_api() {
+        let pair = day04::Pair::new((2, 8), (3, 7));
+        assert_eq!(pair.get_sections(), ((2, 8), (3, 7)));
+        assert!(pair.is_overlapping());
+        assert!(!day04::Pair::new((2, 4), (6, 8)).is_partially_overlapping());
+    }
+}
@@ -0,0 +1,87 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub stacks: usize,
+    pub max_initial_height: i64,
+    pub steps: usize
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { stacks: 9, max_initial_height: 8, steps: 500 };
+    }
+}
+
+/// A crate drawing followed by `move` steps that only ever take crates from non-empty stacks.
+///
+/// `stacks` must be from 1 to 9, for the stack numbers to fit their columns.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let stacks = (0..params.stacks)
+        .map(|_| (0..rng.between(0, params.max_initial_height)).map(|_| (b'A' + rng.below(26) as u8) as char).collect())
+        .collect::<Vec<Vec<char>>>();
+
+    let mut steps = Vec::new();
+    let mut heights = stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+    while steps.len() < params.steps && heights.iter().any(|h| *h > 0) && params.stacks > 1 {
+        let non_empty = (0..params.stacks).filter(|i| heights[*i] > 0).collect::<Vec<usize>>();
+        let from = *rng.choose(&non_empty);
+        let to = (from + 1 + rng.index(params.stacks - 1)) % params.stacks;
+        let quantity = rng.between(1, heights[from] as i64) as usize;
+        heights[from] -= quantity;
+        heights[to] += quantity;
+        steps.push((quantity, from, to));
+    }
+
+    let mut lines = draw(&stacks);
+    lines.push(String::new());
+    lines.extend(steps.iter().map(|(quantity, from, to)| format!("move {} from {} to {}", quantity, from + 1, to + 1)));
+
+    return GeneratedInput {
+        input: lines.join("\n"),
+        part1: Some(top_crates(&rearrange(&stacks, &steps, true))),
+        part2: Some(top_crates(&rearrange(&stacks, &steps, false)))
+    };
+}
+
+fn draw(stacks: &[Vec<char>]) -> Vec<String> {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    let mut lines = (0..height).rev()
+        .map(|level| stacks.iter()
+            .map(|stack| stack.get(level).map(|c| format!("[{}]", c)).unwrap_or("   ".to_string()))
+            .collect::<Vec<String>>()
+            .join(" "))
+        .collect::<Vec<String>>();
+    lines.push((1..=stacks.len()).map(|i| format!(" {} ", i)).collect::<Vec<String>>().join(" "));
+    return lines;
+}
+
+fn rearrange(stacks: &[Vec<char>], steps: &[(usize, usize, usize)], one_at_a_time: bool) -> Vec<Vec<char>> {
+    let mut stacks = stacks.to_vec();
+    for (quantity, from, to) in steps {
+        let split = stacks[*from].len() - quantity;
+        let mut moved = stacks[*from].split_off(split);
+        if one_at_a_time {
+            moved.reverse();
+        }
+        stacks[*to].extend(moved);
+    }
+    return stacks;
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    return stacks.iter().filter_map(|stack| stack.last()).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day05::Day05, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day05), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,347 @@
+pub mod generator;
+
+pub mod day05 {
+    use std::io::BufRead;
+    use std::{fmt, str};
+    use regex::Regex;
+    use tracing::trace;
+    use aoc_common::{input::{read_input, split_sections}, parse::{number_lines, unexpected_end, Line, ParseError}, solution::Solution};
+
+    pub fn get_crates_on_top_of_stacks_after_rearrangement(input: &mut dyn BufRead) -> Result<String, ParseError> {
+        return Day05.part1(&Day05.parse(input)?);
+    }
+
+    pub fn get_crates_on_top_of_stacks_after_rearrangement_using_crane_mover_9001(input: &mut dyn BufRead) -> Result<String, ParseError> {
+        return Day05.part2(&Day05.parse(input)?);
+    }
+
+    pub struct Day05;
+
+    impl Solution for Day05 {
+        type Parsed = (Stacks, RearrangementProcedure);
+        type Answer1 = String;
+        type Answer2 = String;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<(Stacks, RearrangementProcedure), ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, (stacks, procedure): &(Stacks, RearrangementProcedure)) -> Result<String, ParseError> {
+            let mut crane = CrateMover9000::new(stacks.clone());
+            crane.execute(procedure);
+            return Ok(crane.get_stacks().get_top_crates());
+        }
+
+        fn part2(&self, (stacks, procedure): &(Stacks, RearrangementProcedure)) -> Result<String, ParseError> {
+            let mut crane = CrateMover9001::new(stacks.clone());
+            crane.execute(procedure);
+            return Ok(crane.get_stacks().get_top_crates());
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<(Stacks, RearrangementProcedure), ParseError> {
+        let lines: Vec<String> = read_input(input)?;
+        let numbered_lines = number_lines(&lines);
+        let segments = split_sections(&numbered_lines);
+        if segments.len() < 2 {
+            return Err(unexpected_end(numbered_lines.last(), "a blank line followed by the rearrangement procedure"));
+        }
+        let stacks = parse_stacks(segments.first().unwrap())?;
+        let procedure = parse_rearrangament_procedure(segments.last().unwrap(), &stacks)?;
+        return Ok((stacks, procedure));
+    }
+
+    fn parse_stacks(lines: &[Line]) -> Result<Stacks, ParseError> {
+        let numbers_line = lines.last().ok_or(ParseError::new(1, 1, "a drawing of crate stacks"))?;
+        let stack_numbers = parse_stack_numbers(numbers_line)?;
+        let stack_crates: Vec<Vec<Option<char>>> = lines[0..lines.len()-1].iter().map(|line| parse_crates(line)).collect::<Result<_, _>>()?;
+        let stacks = stack_numbers.iter().map(|i| make_stack(*i, &stack_crates)).collect();
+        return Ok(Stacks::new(stacks));
+    }
+
+    fn split_into_cells(line: &Line) -> Vec<(usize, String)> {
+        return line.text.chars()
+            .collect::<Vec<char>>()
+            .chunks(4)
+            .enumerate()
+            .map(|(i, cell)| (i * 4 + 1, cell.iter().collect::<String>().trim().to_string()))
+            .collect();
+    }
+
+    fn parse_stack_numbers(line: &Line) -> Result<Vec<usize>, ParseError> {
+        return split_into_cells(line).iter()
+            .map(|(column, x)| match x.parse::<usize>() {
+                Ok(i) if i > 0 => Ok(i - 1),
+                _ => Err(line.error_at(*column, "a stack number starting from 1"))
+            })
+            .collect();
+    }
+
+    fn parse_crates(line: &Line) -> Result<Vec<Option<char>>, ParseError> {
+        return split_into_cells(line).iter()
+            .map(|(column, x)| {
+                let chars = x.chars().collect::<Vec<char>>();
+                match chars.as_slice() {
+                    [] => Ok(None),
+                    ['[', c, ']'] => Ok(Some(*c)),
+                    _ => Err(line.error_at(*column, "a crate like `[A]` or an empty slot"))
+                }
+            })
+            .collect();
+    }
+
+    fn make_stack(i: usize, crates: &Vec<Vec<Option<char>>>) -> Vec<char> {
+        return crates.iter()
+            .filter_map(|c| c.get(i))
+            .flatten()
+            .copied()
+            .rev()
+            .collect();
+    }
+
+    /// Follows the height of every stack, so that no step moves more crates than its stack holds.
+    fn parse_rearrangament_procedure(lines: &[Line], stacks: &Stacks) -> Result<RearrangementProcedure, ParseError> {
+        let re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        let mut heights = stacks.stacks.iter().map(|stack| stack.len()).collect::<Vec<usize>>();
+        let steps = lines.iter().map(|line| parse_procedure_step(&re, line, &mut heights)).collect::<Result<_, _>>()?;
+        return Ok(RearrangementProcedure::new(steps));
+    }
+
+    fn parse_procedure_step(re: &Regex, line: &Line, heights: &mut [usize]) -> Result<Step, ParseError> {
+        let captures = re.captures(line.text).ok_or(line.error_at(1, "`move <quantity> from <stack> to <stack>`"))?;
+        let quantity_text = captures.get(1).unwrap().as_str();
+        let quantity = line.parse::<usize>(quantity_text, "a crate quantity")?;
+        let from = parse_stack_reference(line, captures.get(2).unwrap().as_str(), heights.len())?;
+        let to = parse_stack_reference(line, captures.get(3).unwrap().as_str(), heights.len())?;
+        if quantity > heights[from] {
+            return Err(line.error_on(quantity_text, format!("at most {} crates, the height of stack {}", heights[from], from + 1)));
+        }
+        heights[from] -= quantity;
+        heights[to] += quantity;
+        return Ok(Step::new(quantity, from, to));
+    }
+
+    fn parse_stack_reference(line: &Line, stack: &str, number_of_stacks: usize) -> Result<usize, ParseError> {
+        let expected = format!("a stack number from 1 to {}", number_of_stacks);
+        return match line.parse::<usize>(stack, &expected)? {
+            i if i >= 1 && i <= number_of_stacks => Ok(i - 1),
+            _ => Err(line.error_on(stack, expected))
+        };
+    }
+
+    #[derive(Clone)]
+    pub struct Stacks {
+        stacks: Vec<Vec<char>>
+    }
+
+    pub struct CrateMover9000 {
+        stacks: Stacks
+    }
+
+    impl CrateMover9000 {
+        pub fn new(stacks: Stacks) -> CrateMover9000 {
+            return CrateMover9000 { stacks };
+        }
+    }
+
+    pub struct CrateMover9001 {
+        stacks: Stacks
+    }
+
+    impl CrateMover9001 {
+        pub fn new(stacks: Stacks) -> CrateMover9001 {
+            return CrateMover9001 { stacks };
+        }
+    }
+
+    /// A crane operating on stacks of crates.
+    ///
+    /// Steps are expected to be valid for the stacks, as the parser checks for
+    /// the puzzle input; moving a crate from an empty or missing stack panics.
+    pub trait CrateMover {
+        fn rearrange(&mut self, step: Step);
+
+        fn get_stacks(&self) -> &Stacks;
+
+        fn execute(&mut self, procedure: &RearrangementProcedure) {
+            for step in procedure.get_steps() {
+                self.rearrange(*step);
+                trace!(step.quantity, step.from, step.to, stacks = %format_args!("\n{}", self.get_stacks()), "crates rearranged");
+            }
+        }
+    }
+
+    impl CrateMover for CrateMover9000 {
+        fn get_stacks(&self) -> &Stacks {
+            return &self.stacks;
+        }
+
+        fn rearrange(&mut self, step: Step) {
+            for _ in 0..step.quantity {
+                let tmp = self.stacks.stacks.get_mut(step.from).unwrap()
+                    .pop().unwrap();
+                self.stacks.stacks.get_mut(step.to).unwrap().push(tmp);
+            }
+        }
+    }
+
+    impl CrateMover for CrateMover9001 {
+        fn get_stacks(&self) -> &Stacks {
+            return &self.stacks;
+        }
+
+        fn rearrange(&mut self, step: Step) {
+            let mut tmp: Vec<char> = Vec::new();
+            for _ in 0..step.quantity {
+                tmp.push(self.stacks.stacks.get_mut(step.from).unwrap().pop().unwrap());
+            }
+
+            for _ in 0..step.quantity {
+                self.stacks.stacks.get_mut(step.to).unwrap().push(tmp.pop().unwrap());
+            }
+        }
+    }
+
+    impl Stacks {
+        /// Stacks of crates, each listed from the bottom up.
+        pub fn new(stacks: Vec<Vec<char>>) -> Stacks {
+            return Stacks { stacks };
+        }
+
+        pub fn get_stack(&self, i: usize) -> Option<&[char]> {
+            return self.stacks.get(i).map(|stack| stack.as_slice());
+        }
+
+        pub fn len(&self) -> usize {
+            return self.stacks.len();
+        }
+
+        pub fn is_empty(&self) -> bool {
+            return self.stacks.is_empty();
+        }
+
+        pub fn get_top_crates(&self) -> String {
+            return self.stacks.iter()
+                .filter_map(|stack| stack.last())
+                .collect();
+        }
+    }
+
+    /// One line per stack, bottom crate first.
+    impl fmt::Display for Stacks {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let lines = self.stacks.iter()
+                .map(|stack| stack.iter().map(|c| format!("[{}]", c)).collect::<Vec<String>>().join(" "))
+                .collect::<Vec<String>>();
+            return write!(f, "{}", lines.join("\n"));
+        }
+    }
+
+    pub struct RearrangementProcedure {
+        steps: Vec<Step>
+    }
+
+    impl RearrangementProcedure {
+        pub fn new(steps: Vec<Step>) -> RearrangementProcedure {
+            return RearrangementProcedure { steps };
+        }
+
+        pub fn get_steps(&self) -> &[Step] {
+            return &self.steps;
+        }
+    }
+
+    /// Moves `quantity` crates between two stacks, numbered from 0.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Step {
+        pub quantity: usize,
+        pub from: usize,
+        pub to: usize
+    }
+
+    impl Step {
+        pub fn new(quantity: usize, from: usize, to: usize) -> Step {
+            return Step { quantity, from, to };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day05;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day05::get_crates_on_top_of_stacks_after_rearrangement(&mut f).unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day05::get_crates_on_top_of_stacks_after_rearrangement(&mut f).unwrap(), "VJSFHWGFT");
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day05::get_crates_on_top_of_stacks_after_rearrangement_using_crane_mover_9001(&mut f).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day05::get_crates_on_top_of_stacks_after_rearrangement_using_crane_mover_9001(&mut f).unwrap(), "LCTQFBVZV");
+    }
+
+    #[test]
+    fn reports_malformed_procedure_step() {
+        let drawing = "[A]     \n[B] [C]\n 1   2 \n\n";
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut format!("{}move 1 from 1 to 2\nmove one from 2 to 1", drawing).as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(6, 1, "`move <quantity> from <stack> to <stack>`"));
+
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut format!("{}move 1 from 1 to 3", drawing).as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(5, 18, "a stack number from 1 to 2"));
+    }
+
+    #[test]
+    fn reports_moves_of_more_crates_than_stacked() {
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut "[A]\n 1 \n\nmove 5 from 1 to 1".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(4, 6, "at most 1 crates, the height of stack 1"));
+
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement_using_crane_mover_9001(&mut "    [D]\n[N] [C]\n 1   2\n\nmove 9 from 2 to 1".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(5, 6, "at most 2 crates, the height of stack 2"));
+
+        // Heights follow the earlier steps of the procedure.
+        let input = "    [D]\n[N] [C]\n 1   2\n\nmove 2 from 2 to 1\nmove 3 from 1 to 2\nmove 4 from 2 to 1";
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut input.as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(7, 6, "at most 3 crates, the height of stack 2"));
+    }
+
+    #[test]
+    fn reports_malformed_drawing() {
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut "[A] (B)\n 1   2 \n\nmove 1 from 1 to 2".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 5, "a crate like `[A]` or an empty slot"));
+
+        let error = day05::get_crates_on_top_of_stacks_after_rearrangement(&mut "[A]\n 1 ".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(3, 1, "a blank line followed by the rearrangement procedure"));
+    }
+
+    #[test]
+    fn operates_cranes_through_library_api() {
+        use day05::CrateMover;
+
+        let stacks = day05::Stacks::new(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+        let procedure = day05::RearrangementProcedure::new(vec![day05::Step::new(2, 1, 0)]);
+
+        let mut crane = day05::CrateMover9000::new(stacks.clone());
+        crane.execute(&procedure);
+        assert_eq!(crane.get_stacks().get_stack(0), Some(&['Z', 'N', 'D', 'C'][..]));
+
+        let mut crane = day05::CrateMover9001::new(stacks);
+        crane.execute(&procedure);
+        assert_eq!(crane.get_stacks().get_top_crates(), "DMP");
+    }
+}
@@ -0,0 +1,50 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub length: usize,
+    pub alphabet_size: u64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { length: 4000, alphabet_size: 16 };
+    }
+}
+
+/// A datastream over the first `alphabet_size` letters, ended with all 26 letters in random
+/// order so that both markers exist.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let mut buffer = (0..params.length)
+        .map(|_| (b'a' + rng.below(params.alphabet_size) as u8) as char)
+        .collect::<Vec<char>>();
+    let mut suffix = ('a'..='z').collect::<Vec<char>>();
+    rng.shuffle(&mut suffix);
+    buffer.extend(suffix);
+
+    return GeneratedInput {
+        input: buffer.iter().collect(),
+        part1: Some(marker_end(&buffer, 4).to_string()),
+        part2: Some(marker_end(&buffer, 14).to_string())
+    };
+}
+
+fn marker_end(buffer: &[char], n: usize) -> usize {
+    let mask = |window: &[char]| window.iter().fold(0u32, |mask, c| mask | 1 << (*c as u32 - 'a' as u32));
+    return (n..=buffer.len())
+        .find(|end| mask(&buffer[end - n..*end]).count_ones() as usize == n)
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day06::Day06, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day06), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+pub mod generator;
+
+pub mod day06 {
+    use std::io::BufRead;
+    use itertools::Itertools;
+    use aoc_common::{input::read_first_line, parse::{Line, ParseError}, solution::Solution};
+
+    const START_OF_PACKET_LENGTH: usize = 4;
+    const START_OF_MESSAGE_LENGTH: usize = 14;
+
+    pub fn locate_first_start_of_packet(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day06.part1(&Day06.parse(input)?);
+    }
+    
+    pub fn locate_first_start_of_message(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day06.part2(&Day06.parse(input)?);
+    }
+
+    pub struct Day06;
+
+    impl Solution for Day06 {
+        type Parsed = Datastream;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Datastream, ParseError> {
+            return parse_input(&read_first_line(input)?);
+        }
+
+        fn part1(&self, datastream: &Datastream) -> Result<usize, ParseError> {
+            return Ok(datastream.start_of_packet);
+        }
+
+        fn part2(&self, datastream: &Datastream) -> Result<usize, ParseError> {
+            return Ok(datastream.start_of_message);
+        }
+    }
+
+    /// Number of characters processed when each marker has been received.
+    pub struct Datastream {
+        pub start_of_packet: usize,
+        pub start_of_message: usize
+    }
+
+    /// Number of characters processed when the first `n` distinct characters in a row have been received.
+    pub fn locate_start_of_unique_sequence_of_characters(buffer: &[char], n: usize) -> Option<usize> {
+        return buffer.windows(n).zip(0..buffer.len())
+            .filter(|(candidate, _)| is_marker(candidate, n))
+            .map(|(_, i)| i)
+            .collect::<Vec<usize>>().first().map(|i| *i + n);
+    }
+
+    /// Fails unless the line contains both markers.
+    fn parse_input(text: &str) -> Result<Datastream, ParseError> {
+        let line = Line::new(1, text);
+        let buffer = text.chars().collect::<Vec<char>>();
+        let locate = |n: usize, marker: &str| {
+            if buffer.len() < n {
+                return Err(line.error_at(line.end_column(), format!("at least {} characters", n)));
+            }
+            return locate_start_of_unique_sequence_of_characters(&buffer, n)
+                .ok_or_else(|| line.error_at(line.end_column(), format!("a start-of-{} marker, {} different characters in a row", marker, n)));
+        };
+        return Ok(Datastream {
+            start_of_packet: locate(START_OF_PACKET_LENGTH, "packet")?,
+            start_of_message: locate(START_OF_MESSAGE_LENGTH, "message")?
+        });
+    }
+
+    fn is_marker(candidate: &&[char], number_of_distinct_characters: usize) -> bool {
+        return candidate.iter().unique().count() == number_of_distinct_characters;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day06;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day06::locate_first_start_of_packet(&mut f).unwrap(), 7);
+    }
+
+    #[test]
+    fn part1_other_sample_inputs() {
+        assert_eq!(day06::locate_first_start_of_packet(&mut "bvwbjplbgvbhsrlpgdmjqwftvncz".as_bytes()).unwrap(), 5);
+        assert_eq!(day06::locate_first_start_of_packet(&mut "nppdvjthqldpwncqszvftbrmjlhg".as_bytes()).unwrap(), 6);
+        assert_eq!(day06::locate_first_start_of_packet(&mut "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_bytes()).unwrap(), 10);
+        assert_eq!(day06::locate_first_start_of_packet(&mut "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".as_bytes()).unwrap(), 11);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day06::locate_first_start_of_packet(&mut f).unwrap(), 1356);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day06::locate_first_start_of_message(&mut f).unwrap(), 19);
+    }
+
+    #[test]
+    fn part2_other_sample_inputs() {
+        assert_eq!(day06::locate_first_start_of_message(&mut "bvwbjplbgvbhsrlpgdmjqwftvncz".as_bytes()).unwrap(), 23);
+        assert_eq!(day06::locate_first_start_of_message(&mut "nppdvjthqldpwncqszvftbrmjlhg".as_bytes()).unwrap(), 23);
+        assert_eq!(day06::locate_first_start_of_message(&mut "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_bytes()).unwrap(), 29);
+        assert_eq!(day06::locate_first_start_of_message(&mut "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".as_bytes()).unwrap(), 26);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day06::locate_first_start_of_message(&mut f).unwrap(), 2564);
+    }
+
+    #[test]
+    fn rejects_datastreams_without_markers() {
+        assert_eq!(day06::locate_first_start_of_packet(&mut "".as_bytes()), Err(ParseError::new(1, 1, "at least 4 characters")));
+        assert_eq!(day06::locate_first_start_of_packet(&mut "abc".as_bytes()), Err(ParseError::new(1, 4, "at least 4 characters")));
+        assert_eq!(day06::locate_first_start_of_packet(&mut "abababab".as_bytes()), Err(ParseError::new(1, 9, "a start-of-packet marker, 4 different characters in a row")));
+        assert_eq!(day06::locate_first_start_of_message(&mut "abcdabcdabcdabcd".as_bytes()), Err(ParseError::new(1, 17, "a start-of-message marker, 14 different characters in a row")));
+    }
+
+    #[test]
+    fn locates_markers_through_library_api() {
+        let buffer = "bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect::<Vec<char>>();
+        assert_eq!(day06::locate_start_of_unique_sequence_of_characters(&buffer, 4), Some(5));
+        assert_eq!(day06::locate_start_of_unique_sequence_of_characters(&buffer, 30), None);
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+use crate::day07::Day07;
+
+pub struct Params {
+    pub max_depth: usize,
+    pub max_subdirectories: i64,
+    pub max_files: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { max_depth: 6, max_subdirectories: 4, max_files: 6 };
+    }
+}
+
+const EXTENSIONS: [&str; 4] = [".txt", ".dat", ".log", ".bin"];
+
+struct Directory {
+    name: String,
+    files: Vec<(String, u32)>,
+    subdirectories: Vec<Directory>
+}
+
+/// A `cd`/`ls` transcript walking a random directory tree depth first.
+///
+/// Every directory holds at least one file. The answers assume the disk and
+/// update sizes of `Day07::default()`.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let root = generate_directory(&mut rng, params, "/".to_string(), 0);
+
+    let mut lines = vec!["$ cd /".to_string()];
+    transcribe(&root, &mut lines);
+
+    let mut sizes = Vec::new();
+    let used = directory_sizes(&root, &mut sizes);
+    let day = Day07::default();
+    let missing = day.free_space_needed_for_update.saturating_sub(day.total_disk_space.saturating_sub(used));
+
+    return GeneratedInput {
+        input: lines.join("\n"),
+        part1: Some(sizes.iter().filter(|size| **size <= 100000).sum::<u32>().to_string()),
+        part2: Some(sizes.iter().filter(|size| **size >= missing).min().copied().unwrap_or(0).to_string())
+    };
+}
+
+fn generate_directory(rng: &mut Rng, params: &Params, name: String, depth: usize) -> Directory {
+    let mut names = Vec::new();
+    let files = (0..rng.between(1, params.max_files))
+        .map(|_| {
+            let name = unique_name(rng, &mut names, true);
+            let magnitude = 10i64.pow(rng.between(2, 6) as u32);
+            return (name, rng.between(1, magnitude) as u32);
+        })
+        .collect();
+    let subdirectories = if depth < params.max_depth {
+        (0..rng.between(0, params.max_subdirectories))
+            .map(|_| {
+                let name = unique_name(rng, &mut names, false);
+                return generate_directory(rng, params, name, depth + 1);
+            })
+            .collect()
+    }
+    else {
+        Vec::new()
+    };
+    return Directory { name, files, subdirectories };
+}
+
+fn unique_name(rng: &mut Rng, taken: &mut Vec<String>, with_extension: bool) -> String {
+    loop {
+        let mut name = (0..rng.between(1, 8)).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+        if with_extension && rng.chance(1, 2) {
+            let extension = rng.choose(&EXTENSIONS);
+            name.push_str(extension);
+        }
+        if !taken.contains(&name) {
+            taken.push(name.clone());
+            return name;
+        }
+    }
+}
+
+fn transcribe(directory: &Directory, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    lines.extend(directory.subdirectories.iter().map(|d| format!("dir {}", d.name)));
+    lines.extend(directory.files.iter().map(|(name, size)| format!("{} {}", size, name)));
+    for subdirectory in directory.subdirectories.iter() {
+        lines.push(format!("$ cd {}", subdirectory.name));
+        transcribe(subdirectory, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+fn directory_sizes(directory: &Directory, sizes: &mut Vec<u32>) -> u32 {
+    let size = directory.files.iter().map(|(_, size)| size).sum::<u32>()
+        + directory.subdirectories.iter().map(|d| directory_sizes(d, sizes)).sum::<u32>();
+    sizes.push(size);
+    return size;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day07::Day07, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day07::default()), Ok(()), "seed {}", seed);
+        }
+    }
+}
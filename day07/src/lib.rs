@@ -0,0 +1,312 @@
+pub mod generator;
+
+pub mod day07 {
+    use std::io::BufRead;
+    use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use tracing::{trace, Level};
+
+    pub fn sum_of_directories_of_size_at_most_100000(input: &mut dyn BufRead) -> Result<u32, ParseError> {
+        let day = Day07::default();
+        return day.part1(&day.parse(input)?);
+    }
+
+    pub fn find_smallest_directory_size_that_would_free_up_enough_space_for_update(input: &mut dyn BufRead) -> Result<u32, ParseError> {
+        let day = Day07::default();
+        return day.part2(&day.parse(input)?);
+    }
+
+    pub struct Day07 {
+        pub total_disk_space: u32,
+        pub free_space_needed_for_update: u32
+    }
+
+    impl Default for Day07 {
+        fn default() -> Day07 {
+            return Day07 { total_disk_space: 70000000, free_space_needed_for_update: 30000000 };
+        }
+    }
+
+    impl Solution for Day07 {
+        type Parsed = FileSystem;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<FileSystem, ParseError> {
+            return FileSystem::from_terminal_output(&read_input(input)?, self.total_disk_space);
+        }
+
+        fn part1(&self, fs: &FileSystem) -> Result<u32, ParseError> {
+            fs.trace_tree();
+            return Ok(fs.find_sum_of_size_of_directories_with_a_size_of_at_most(100000));
+        }
+
+        fn part2(&self, fs: &FileSystem) -> Result<u32, ParseError> {
+            return Ok(fs.find_smallest_directory_size_that_would_free_up_enough_space(self.free_space_needed_for_update));
+        }
+    }
+
+    struct OutputInterpreter {
+        root: Rc<RefCell<FileNode>>,
+        current: Rc<RefCell<FileNode>>
+    }
+
+    impl OutputInterpreter {
+        fn interpret(&mut self, line: &Line) -> Result<(), ParseError> {
+            return match line.text.chars().next() {
+                Some('$') => self.interpret_command(line),
+                Some(_) => self.interpret_output(line),
+                None => Err(line.error_at(1, "a command or a listing entry"))
+            };
+        }
+
+        fn interpret_command(&mut self, line: &Line) -> Result<(), ParseError> {
+            let mut parts = line.text.split(' ').skip(1);
+            let cmd = parts.next().unwrap_or("");
+
+            return match cmd {
+                "cd" => {
+                    let arg = parts.next().ok_or_else(|| line.error_at(line.end_column(), "a directory to change to"))?;
+                    self.interpret_cd(arg).map_err(|expected| line.error_on(arg, expected))
+                },
+                "ls" => Ok(()),
+                _ => Err(line.error_at(3, "`cd` or `ls`"))
+            };
+        }
+
+        fn interpret_output(&mut self, line: &Line) -> Result<(), ParseError> {
+            if line.text.starts_with("dir ") {
+                let dir_name = line.text.split_once(" ").unwrap().1;
+                self.add_dir(dir_name);
+            }
+            else {
+                let (size, file_name) = line.text.split_once(" ")
+                    .ok_or_else(|| line.error_at(1, "`dir <name>` or `<size> <name>`"))?;
+                self.add_file(file_name, line.parse::<u32>(size, "a file size")?);
+            }
+            return Ok(());
+        }
+
+        fn add_dir(&mut self, name: &str) {
+            let child = Rc::new(RefCell::new(FileNode::new()));
+            let mut child_mut = child.borrow_mut();
+            child_mut.name = name.to_string();
+            child_mut.directory = true;
+            child_mut.parent = Some(Rc::clone(&self.current));
+
+            self.current.borrow_mut().add_child(Rc::clone(&child));
+        }
+
+        fn add_file(&mut self, name: &str, size: u32) {
+            let child = Rc::new(RefCell::new(FileNode::new()));
+            let mut child_mut = child.borrow_mut();
+            child_mut.name = name.to_string();
+            child_mut.parent = Some(Rc::clone(&self.current));
+            child_mut.size = Some(size);
+
+            self.current.borrow_mut().add_child(Rc::clone(&child));
+        }
+
+        fn interpret_cd(&mut self, arg: &str) -> Result<(), &'static str> {
+            return match arg {
+                ".." => self.move_out_one_level(),
+                "/" => self.switch_to_outermost_directory(),
+                _ => self.move_in_one_level(arg)
+            };
+        }
+
+        fn move_out_one_level(&mut self) -> Result<(), &'static str> {
+            let u = Rc::clone(self.current.borrow().parent.as_ref().ok_or("a directory below `/` to move out of")?);
+            self.current = u;
+            return Ok(());
+        }
+
+        fn switch_to_outermost_directory(&mut self) -> Result<(), &'static str> {
+            self.current = Rc::clone(&self.root);
+            return Ok(());
+        }
+
+        fn move_in_one_level(&mut self, dir_name: &str) -> Result<(), &'static str> {
+            let u = Rc::clone(self.current.borrow().children.iter().find(|c| c.borrow().name.eq(dir_name)).ok_or("a directory listed by `ls`")?);
+            self.current = u;
+            return Ok(());
+        }
+    }
+
+    pub struct FileSystem {
+        total_disk_space: u32,
+        root: Rc<RefCell<FileNode>>
+    }
+
+    impl FileSystem {
+        /// Rebuilds the file system browsed by the given `cd` and `ls` session.
+        pub fn from_terminal_output(lines: &[String], total_disk_space: u32) -> Result<FileSystem, ParseError> {
+            let root = Rc::new(RefCell::new(FileNode::new()));
+            let current = Rc::clone(&root);
+            current.borrow_mut().name = "/".to_string();
+            current.borrow_mut().directory = true;
+            let root_ref = Rc::clone(&root);
+
+            let mut i = OutputInterpreter {root: root_ref, current: current};
+            for line in number_lines(lines).iter() {
+                i.interpret(line)?;
+            }
+            let mut fs = FileSystem {root: Rc::clone(&root), total_disk_space};
+            fs.calculate_sizes();
+            return Ok(fs);
+        }
+
+        pub fn get_total_disk_space(&self) -> u32 {
+            return self.total_disk_space;
+        }
+
+        pub fn get_used_space(&self) -> u32 {
+            return self.root.borrow().size.unwrap();
+        }
+
+        /// Total sizes of all directories, the outermost one included.
+        pub fn get_directory_sizes(&self) -> Vec<u32> {
+            return self.collect_directories(Rc::clone(&self.root)).iter()
+                .map(|d| d.borrow().size.unwrap())
+                .collect();
+        }
+
+        fn calculate_sizes(&mut self) {
+            self.calculate_size(Rc::clone(&self.root));
+        }
+
+        /// Directories never listed, or listed empty, have a size of 0.
+        fn calculate_size(&mut self, node: Rc<RefCell<FileNode>>) -> u32 {
+            if node.borrow().directory {
+                let u: u32 = node.borrow().children.iter().map(|c| self.calculate_size(Rc::clone(c))).sum();
+                node.borrow_mut().size = Some(u);
+                return node.borrow().size.unwrap();
+            }
+            else {
+                return node.borrow().size.unwrap();
+            }
+        }
+
+        pub fn find_sum_of_size_of_directories_with_a_size_of_at_most(&self, threshold: u32) -> u32 {
+            return self.get_directory_sizes().iter().filter(|s| **s <= threshold).sum();
+        }
+
+        fn collect_directories(&self, node: Rc<RefCell<FileNode>>) -> Vec<Rc<RefCell<FileNode>>> {
+            let mut nested = node.borrow().children.iter()
+                .filter(|c| c.borrow().directory)
+                .flat_map(|c| self.collect_directories(Rc::clone(c)))
+                .collect::<Vec<Rc<RefCell<FileNode>>>>();
+            nested.push(node);
+            return nested;
+        }
+
+        /// Emits a trace event for every file and directory, depth first.
+        fn trace_tree(&self) {
+            if tracing::enabled!(Level::TRACE) {
+                self.trace_node(Rc::clone(&self.root), 0);
+            }
+        }
+
+        fn trace_node(&self, node: Rc<RefCell<FileNode>>, depth: usize) {
+            let directory = node.borrow().directory;
+            trace!(depth, name = %node.borrow().name, size = node.borrow().size.unwrap(), directory, "file system entry");
+            for n in node.borrow().children.iter() {
+                self.trace_node(Rc::clone(n), depth + 1)
+            }
+        }
+
+        /// Size of the smallest directory whose deletion leaves at least `free_at_latest` unused, or 0 if none does.
+        pub fn find_smallest_directory_size_that_would_free_up_enough_space(&self, free_at_latest: u32) -> u32 {
+            let currently_unused_space = self.total_disk_space.saturating_sub(self.get_used_space());
+            let missing_free_space = free_at_latest.saturating_sub(currently_unused_space);
+
+            let mut sizes = self.get_directory_sizes();
+            sizes.sort();
+            return sizes.iter().find(|s| **s > missing_free_space).copied().unwrap_or(0);
+        }
+    }
+
+    struct FileNode {
+        name: String,
+        size: Option<u32>,
+        directory: bool,
+        children: Vec<Rc<RefCell<FileNode>>>,
+        parent: Option<Rc<RefCell<FileNode>>>
+    }
+
+    impl FileNode {
+        pub fn new() -> FileNode {
+            return FileNode { name: "".to_string(), size: None, directory: false, children: vec![], parent: None }
+        }
+
+        pub fn add_child(&mut self, new_node: Rc<RefCell<FileNode>>) {
+            self.children.push(new_node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day07;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day07::sum_of_directories_of_size_at_most_100000(&mut f).unwrap(), 95437)
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day07::sum_of_directories_of_size_at_most_100000(&mut f).unwrap(), 1086293)
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day07::find_smallest_directory_size_that_would_free_up_enough_space_for_update(&mut f).unwrap(), 24933642)
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day07::find_smallest_directory_size_that_would_free_up_enough_space_for_update(&mut f).unwrap(), 366028)
+    }
+
+    #[test]
+    fn reports_change_into_unknown_directory() {
+        let error = day07::sum_of_directories_of_size_at_most_100000(&mut "$ cd /\n$ ls\ndir a\n$ cd b".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(4, 6, "a directory listed by `ls`"));
+    }
+
+    #[test]
+    fn reports_malformed_file_size() {
+        let error = day07::sum_of_directories_of_size_at_most_100000(&mut "$ cd /\n$ ls\n12k a.txt".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(3, 1, "a file size"));
+    }
+
+    #[test]
+    fn sizes_empty_and_unlisted_directories_as_zero() {
+        assert_eq!(day07::sum_of_directories_of_size_at_most_100000(&mut "".as_bytes()), Ok(0));
+        assert_eq!(day07::sum_of_directories_of_size_at_most_100000(&mut "$ cd /\n$ ls\ndir a".as_bytes()), Ok(0));
+
+        let output = ["$ cd /", "$ ls", "dir a", "dir b", "10 c.txt", "$ cd a", "$ ls"].map(String::from);
+        let fs = day07::FileSystem::from_terminal_output(&output, 100).unwrap();
+        assert_eq!(fs.get_used_space(), 10);
+        assert_eq!(fs.get_directory_sizes(), vec![0, 0, 10]);
+        assert_eq!(fs.find_smallest_directory_size_that_would_free_up_enough_space(95), 10);
+    }
+
+    #[test]
+    fn queries_file_system_through_library_api() {
+        let output = ["$ cd /", "$ ls", "dir a", "100 b.txt", "$ cd a", "$ ls", "50 c.txt"].map(String::from);
+        let fs = day07::FileSystem::from_terminal_output(&output, 1000).unwrap();
+        assert_eq!(fs.get_used_space(), 150);
+        assert_eq!(fs.get_directory_sizes(), vec![50, 150]);
+        assert_eq!(fs.find_sum_of_size_of_directories_with_a_size_of_at_most(100), 50);
+        assert_eq!(fs.find_smallest_directory_size_that_would_free_up_enough_space(900), 150);
+    }
+}
@@ -0,0 +1,76 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub width: usize,
+    pub height: usize
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { width: 40, height: 40 };
+    }
+}
+
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// A `width` by `height` grid of random tree heights.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let heights = (0..params.height)
+        .map(|_| (0..params.width).map(|_| rng.below(10) as u8).collect())
+        .collect::<Vec<Vec<u8>>>();
+
+    let input = heights.iter()
+        .map(|row| row.iter().map(|height| height.to_string()).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let mut visible = 0;
+    let mut best_score = 0;
+    for y in 0..params.height {
+        for x in 0..params.width {
+            let views = DIRECTIONS.iter().map(|direction| look(&heights, x, y, *direction)).collect::<Vec<(u32, bool)>>();
+            if views.iter().any(|(_, reaches_edge)| *reaches_edge) {
+                visible += 1;
+            }
+            best_score = best_score.max(views.iter().map(|(distance, _)| distance).product());
+        }
+    }
+
+    return GeneratedInput {
+        input,
+        part1: Some(visible.to_string()),
+        part2: Some(best_score.to_string())
+    };
+}
+
+/// Viewing distance from the tree at `(x, y)` in one direction, and whether it can be seen from that edge.
+fn look(heights: &[Vec<u8>], x: usize, y: usize, (dx, dy): (isize, isize)) -> (u32, bool) {
+    let height = heights[y][x];
+    let (mut cx, mut cy) = (x as isize, y as isize);
+    let mut distance = 0;
+    loop {
+        cx += dx;
+        cy += dy;
+        if cy < 0 || cy as usize >= heights.len() || cx < 0 || cx as usize >= heights[0].len() {
+            return (distance, true);
+        }
+        distance += 1;
+        if heights[cy as usize][cx as usize] >= height {
+            return (distance, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day08::Day08, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day08), Ok(()), "seed {}", seed);
+        }
+    }
+}
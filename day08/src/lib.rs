@@ -0,0 +1,190 @@
+pub mod generator;
+
+pub mod day08 {
+    use std::io::BufRead;
+    use take_until::TakeUntilExt;
+    use aoc_common::{geometry::Point, grid::{parse_grid, Grid}, input::read_input, parse::{number_lines, ParseError}, render::{Colour, Render}, solution::Solution};
+
+    pub fn how_many_trees_are_visible_from_outside_the_grid(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day08.part1(&Day08.parse(input)?);
+    }
+
+    pub fn what_is_the_highest_scenic_score_possible_for_any_tree(input: &mut dyn BufRead) -> Result<u32, ParseError> {
+        return Day08.part2(&Day08.parse(input)?);
+    }
+
+    /// The forest, shaded from light (height 0) to dark green (height 9).
+    pub fn picture(input: &mut dyn BufRead) -> Result<Box<dyn Render>, ParseError> {
+        return Ok(Box::new(Day08.parse(input)?));
+    }
+
+    pub struct Day08;
+
+    impl Solution for Day08 {
+        type Parsed = Forest;
+        type Answer1 = usize;
+        type Answer2 = u32;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Forest, ParseError> {
+            return Ok(Forest::new(parse_input(input)?));
+        }
+
+        fn part1(&self, forest: &Forest) -> Result<usize, ParseError> {
+            return Ok(forest.how_many_trees_are_visible_from_outside_the_grid());
+        }
+
+        fn part2(&self, forest: &Forest) -> Result<u32, ParseError> {
+            return Ok(forest.what_is_the_highest_scenic_score_possible_for_any_tree());
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Grid<u8>, ParseError> {
+        let lines = read_input(input)?;
+        return parse_grid(&number_lines(&lines), "a tree height digit", |c| c.to_digit(10).map(|height| height as u8));
+    }
+
+    pub struct Forest {
+        grid: Grid<u8>
+    }
+
+    impl Forest {
+        /// A forest of tree heights, indexed by `(x, y)` from the top left corner.
+        pub fn new(grid: Grid<u8>) -> Forest {
+            return Forest { grid };
+        }
+
+        pub fn get_tree_height(&self, x: usize, y: usize) -> u8 {
+            return *self.grid.get(x, y);
+        }
+
+        pub fn how_many_trees_are_visible_from_outside_the_grid(&self) -> usize {
+            return self.get_trees_coords().iter()
+                .filter(|p| self.is_visible_from_outside(p.x, p.y))
+                .count();
+        }
+
+        pub fn what_is_the_highest_scenic_score_possible_for_any_tree(&self) -> u32 {
+            return self.get_trees_coords().iter()
+                .map(|p| self.calculate_scenic_score(p.x, p.y))
+                .max().unwrap_or(0);
+        }
+
+        fn get_trees_coords(&self) -> Vec<Point<usize>> {
+            return self.grid.coords();
+        }
+
+        pub fn calculate_scenic_score(&self, x: usize, y: usize) -> u32 {
+            let row = self.get_row(y);
+            let col = self.get_column(x);
+            return self.get_viewing_distance(x, &row) * self.get_viewing_distance(y, &col);
+        }
+
+        fn get_viewing_distance(&self, position: usize, series: &[u8]) -> u32 {
+            let height = series.get(position).unwrap();
+            return (0..position).rev().take_until(|p| series.get(*p).unwrap() >= height).count() as u32
+                * (position+1..series.len()).take_until(|p| series.get(*p).unwrap() >= height).count() as u32;
+        }
+
+        pub fn is_visible_from_outside(&self, x: usize, y: usize) -> bool {
+            return self.is_on_edge(x, y) || self.is_visible_in_interior(x, y);
+        }
+
+        fn is_on_edge(&self, x: usize, y: usize) -> bool {
+            return x == 0 || x + 1 == self.grid.width()
+                || y == 0 || y + 1 == self.grid.height();
+        }
+
+        fn is_visible_in_interior(&self, x: usize, y: usize) -> bool {
+            let row: Vec<u8> = self.get_row(y);
+            let column: Vec<u8> = self.get_column(x);
+            return self.is_visible_in_series_interior(x, &row) 
+                || self.is_visible_in_series_interior(y, &column);
+        }
+
+        fn get_row(&self, y: usize) -> Vec<u8> {
+            return self.grid.row(y);
+        }
+
+        fn get_column(&self, x: usize) -> Vec<u8> {
+            return self.grid.column(x);
+        }
+
+        fn is_visible_in_series_interior(&self, position: usize, series: &[u8]) -> bool {
+            let height = series.get(position).unwrap();
+            return (0..position).all(|p| series.get(p).unwrap() < height)
+                || (position + 1..series.len()).all(|p| series.get(p).unwrap() < height);
+        }
+    }
+
+    const LOWEST_TREE: Colour = Colour::rgb(200, 240, 180);
+    const HIGHEST_TREE: Colour = Colour::rgb(10, 70, 20);
+
+    impl Render for Forest {
+        fn width(&self) -> usize {
+            return self.grid.width();
+        }
+
+        fn height(&self) -> usize {
+            return self.grid.height();
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            return LOWEST_TREE.mix(&HIGHEST_TREE, self.get_tree_height(x, y) as usize, 9);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day08;
+    use aoc_common::grid::Grid;
+    use aoc_common::render::Colour;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day08::how_many_trees_are_visible_from_outside_the_grid(&mut f).unwrap(), 21);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day08::how_many_trees_are_visible_from_outside_the_grid(&mut f).unwrap(), 1779);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day08::what_is_the_highest_scenic_score_possible_for_any_tree(&mut f).unwrap(), 8);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day08::what_is_the_highest_scenic_score_possible_for_any_tree(&mut f).unwrap(), 172224);
+    }
+
+    #[test]
+    fn reports_non_digit_tree() {
+        let error = day08::how_many_trees_are_visible_from_outside_the_grid(&mut "303\n2.5".as_bytes()).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "a tree height digit"));
+    }
+
+    #[test]
+    fn inspects_trees_through_library_api() {
+        let forest = day08::Forest::new(Grid::new(vec![vec![3, 0, 3], vec![2, 5, 5], vec![6, 5, 3]]));
+        assert_eq!(forest.get_tree_height(1, 1), 5);
+        assert!(forest.is_visible_from_outside(1, 1));
+        assert_eq!(forest.calculate_scenic_score(1, 1), 1);
+    }
+
+    #[test]
+    fn renders_tree_heights() {
+        let picture = day08::picture(&mut "09\n90".as_bytes()).unwrap();
+        assert_eq!((picture.width(), picture.height()), (2, 2));
+        assert_eq!(picture.colour(0, 0), Colour::rgb(200, 240, 180));
+        assert_eq!(picture.colour(1, 0), Colour::rgb(10, 70, 20));
+    }
+}
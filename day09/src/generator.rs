@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub moves: usize,
+    pub max_distance: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { moves: 2000, max_distance: 20 };
+    }
+}
+
+const DIRECTIONS: [(char, (i64, i64)); 4] = [('L', (-1, 0)), ('R', (1, 0)), ('U', (0, 1)), ('D', (0, -1))];
+
+/// `moves` random head motions; the answers are for ropes of 2 and 10 knots.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let moves = (0..params.moves)
+        .map(|_| (*rng.choose(&DIRECTIONS), rng.between(1, params.max_distance)))
+        .collect::<Vec<((char, (i64, i64)), i64)>>();
+
+    let input = moves.iter()
+        .map(|((letter, _), distance)| format!("{} {}", letter, distance))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let steps = moves.iter()
+        .flat_map(|((_, delta), distance)| (0..*distance).map(move |_| *delta))
+        .collect::<Vec<(i64, i64)>>();
+
+    return GeneratedInput {
+        input,
+        part1: Some(simulate(&steps, 2).to_string()),
+        part2: Some(simulate(&steps, 10).to_string())
+    };
+}
+
+fn simulate(steps: &[(i64, i64)], knots: usize) -> usize {
+    let mut rope = vec![(0i64, 0i64); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for (dx, dy) in steps.iter() {
+        rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+        for i in 1..knots {
+            let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+            if x.abs() > 1 || y.abs() > 1 {
+                rope[i] = (rope[i].0 + x.signum(), rope[i].1 + y.signum());
+            }
+        }
+        visited.insert(rope[knots - 1]);
+    }
+    return visited.len();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day09::Day09, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day09::default()), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+pub mod generator;
+
+pub mod day09 {
+    use std::{io::BufRead, collections::HashSet};
+    use aoc_common::{geometry::{Bounds, Point}, input::read_input, parse::{number_lines, Line, ParseError}, render::{Colour, Render}, solution::Solution};
+
+    pub fn how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(input: &mut dyn BufRead, number_of_knots: usize) -> Result<usize, ParseError> {
+        let moves = parse_input(input)?;
+        return Ok(count_positions_visited_by_tail(&moves, number_of_knots));
+    }
+
+    pub fn count_positions_visited_by_tail(moves: &[(Direction, usize)], number_of_knots: usize) -> usize {
+        let mut sim = Simulator::new(number_of_knots);
+
+        for m in moves.iter() {
+            sim.proceed(m.0, m.1);
+        }
+
+        return sim.get_number_of_unique_positions_visited_by_tail();
+    }
+
+    /// The long rope after all the moves, over the positions visited by its tail.
+    pub fn picture(input: &mut dyn BufRead) -> Result<Box<dyn Render>, ParseError> {
+        let mut sim = Simulator::new(Day09::default().long_rope_knots);
+        for m in parse_input(input)?.iter() {
+            sim.proceed(m.0, m.1);
+        }
+        return Ok(Box::new(sim.picture()));
+    }
+
+    pub struct Day09 {
+        pub short_rope_knots: usize,
+        pub long_rope_knots: usize
+    }
+
+    impl Default for Day09 {
+        fn default() -> Day09 {
+            return Day09 { short_rope_knots: 2, long_rope_knots: 10 };
+        }
+    }
+
+    impl Solution for Day09 {
+        type Parsed = Vec<(Direction, usize)>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<(Direction, usize)>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, moves: &Vec<(Direction, usize)>) -> Result<usize, ParseError> {
+            return Ok(count_positions_visited_by_tail(moves, self.short_rope_knots));
+        }
+
+        fn part2(&self, moves: &Vec<(Direction, usize)>) -> Result<usize, ParseError> {
+            return Ok(count_positions_visited_by_tail(moves, self.long_rope_knots));
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<(Direction, usize)>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines).iter().map(|line| parse_move(line)).collect();
+    }
+
+    fn parse_move(line: &Line) -> Result<(Direction, usize), ParseError> {
+        let (dir, dist) = line.text.split_once(' ')
+            .ok_or_else(|| line.error_at(line.end_column(), "a space between direction and distance"))?;
+        return Ok((parse_direction(line, dir)?, parse_distance(line, dist)?));
+    }
+
+    fn parse_direction(line: &Line, direction: &str) -> Result<Direction, ParseError> {
+        return match direction {
+            "L" => Ok(Direction::L),
+            "R" => Ok(Direction::R),
+            "U" => Ok(Direction::U),
+            "D" => Ok(Direction::D),
+            _ => Err(line.error_on(direction, "L, R, U or D"))
+        };
+    }
+
+    fn parse_distance(line: &Line, distance: &str) -> Result<usize, ParseError> {
+        return line.parse::<usize>(distance, "a distance");
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum Direction {
+        R,
+        L,
+        U,
+        D
+    }
+
+    impl Direction {
+        /// Unit step in this direction, with `y` growing upwards.
+        pub fn delta(&self) -> Point<i32> {
+            return match self {
+                Direction::L => Point::new(-1, 0),
+                Direction::R => Point::new(1, 0),
+                Direction::U => Point::new(0, 1),
+                Direction::D => Point::new(0, -1)
+            };
+        }
+    }
+
+    /// A rope of knots, all starting at the origin, whose head is moved step by step.
+    pub struct Simulator {
+        knots: Vec<Point<i32>>,
+        visited_positions_by_tail: HashSet<Point<i32>>
+    }
+
+    impl Simulator {
+        pub fn new(number_of_knots: usize) -> Simulator {
+            return Simulator { knots: vec![Point::default(); number_of_knots], visited_positions_by_tail: HashSet::from([Point::default()]) };
+        }
+
+        pub fn proceed(&mut self, direction: Direction, distance: usize) {
+            for _ in 0..distance {
+                self.knots[0] += direction.delta();
+                self.move_knots();
+            }
+        }
+
+        fn move_knots(&mut self) {
+            for index in 1..self.knots.len() {
+                let predecessor = self.knots[index - 1];
+                if self.knots[index].chebyshev_distance(&predecessor) > 1 {
+                    self.knots[index] = self.knots[index].step_towards(&predecessor);
+                }
+            }
+            if let Some(tail) = self.knots.last() {
+                self.visited_positions_by_tail.insert(*tail);
+            }
+        }
+
+        /// Positions of the knots, head first.
+        pub fn get_knots(&self) -> &[Point<i32>] {
+            return &self.knots;
+        }
+
+        pub fn get_number_of_unique_positions_visited_by_tail(&self) -> usize {
+            return self.visited_positions_by_tail.len();
+        }
+
+        /// Snapshot of the rope and the positions visited by its tail.
+        pub fn picture(&self) -> RopePicture {
+            let points = self.knots.iter().chain(self.visited_positions_by_tail.iter());
+            let bounds = Bounds::enclosing(points).unwrap_or(Bounds::new(Point::default(), Point::default()));
+            return RopePicture { bounds, knots: self.knots.clone(), visited_positions_by_tail: self.visited_positions_by_tail.clone() };
+        }
+    }
+
+    /// Rope drawn with `y` growing upwards: the head in red, the other knots in orange
+    /// and the positions visited by the tail in grey.
+    pub struct RopePicture {
+        bounds: Bounds<i32>,
+        knots: Vec<Point<i32>>,
+        visited_positions_by_tail: HashSet<Point<i32>>
+    }
+
+    impl Render for RopePicture {
+        fn width(&self) -> usize {
+            return self.bounds.width() as usize;
+        }
+
+        fn height(&self) -> usize {
+            return self.bounds.height() as usize;
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            let point = Point::new(self.bounds.min.x + x as i32, self.bounds.max.y - y as i32);
+            return match self.knots.iter().position(|knot| *knot == point) {
+                Some(0) => Colour::rgb(220, 30, 30),
+                Some(_) => Colour::rgb(250, 160, 40),
+                None if self.visited_positions_by_tail.contains(&point) => Colour::rgb(150, 150, 150),
+                None => Colour::WHITE
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day09;
+    use aoc_common::geometry::Point;
+    use aoc_common::render::{Colour, Render};
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day09::how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(&mut f, 2).unwrap(), 13);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day09::how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(&mut f, 2).unwrap(), 6470);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day09::how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(&mut f, 10).unwrap(), 1);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day09::how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(&mut f, 10).unwrap(), 2658);
+    }
+
+    #[test]
+    fn reports_unknown_direction() {
+        let error = day09::how_many_positions_does_the_tail_of_the_rope_visit_at_least_once(&mut "R 4\nX 2".as_bytes(), 2).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "L, R, U or D"));
+    }
+
+    #[test]
+    fn simulates_rope_through_library_api() {
+        let mut simulator = day09::Simulator::new(2);
+        simulator.proceed(day09::Direction::R, 3);
+        simulator.proceed(day09::Direction::U, 2);
+        assert_eq!(simulator.get_knots(), &[Point::new(3, 2), Point::new(3, 1)]);
+        assert_eq!(simulator.get_number_of_unique_positions_visited_by_tail(), 4);
+    }
+
+    #[test]
+    fn renders_rope_upwards() {
+        let mut simulator = day09::Simulator::new(2);
+        simulator.proceed(day09::Direction::R, 3);
+        simulator.proceed(day09::Direction::U, 2);
+        let picture = simulator.picture();
+        assert_eq!((picture.width(), picture.height()), (4, 3));
+        assert_eq!(picture.colour(3, 0), Colour::rgb(220, 30, 30));
+        assert_eq!(picture.colour(3, 1), Colour::rgb(250, 160, 40));
+        assert_eq!(picture.colour(0, 2), Colour::rgb(150, 150, 150));
+        assert_eq!(picture.colour(0, 0), Colour::WHITE);
+    }
+}
@@ -0,0 +1,64 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub cycles: usize,
+    pub max_step: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { cycles: 240, max_step: 15 };
+    }
+}
+
+/// A program lasting exactly `cycles` cycles (at most the 240 the screen can show)
+/// that keeps the sprite between columns 0 and 39.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let mut lines = Vec::new();
+    let mut values_during_cycles = Vec::new();
+    let mut x = 1i64;
+
+    while values_during_cycles.len() < params.cycles {
+        if values_during_cycles.len() + 1 == params.cycles || rng.chance(1, 3) {
+            lines.push("noop".to_string());
+            values_during_cycles.push(x);
+        }
+        else {
+            let value = rng.between((-x).max(-params.max_step), (39 - x).min(params.max_step));
+            lines.push(format!("addx {}", value));
+            values_during_cycles.extend([x, x]);
+            x += value;
+        }
+    }
+
+    let signal_strength = [20, 60, 100, 140, 180, 220].iter()
+        .filter(|cycle| **cycle <= values_during_cycles.len())
+        .map(|cycle| *cycle as i64 * values_during_cycles[cycle - 1])
+        .sum::<i64>();
+
+    let mut screen = vec![vec![' '; 40]; 6];
+    for (index, sprite) in values_during_cycles.iter().enumerate() {
+        let column = (index % 40) as i64;
+        screen[index / 40][index % 40] = if (sprite - column).abs() <= 1 { '#' } else { '.' };
+    }
+
+    return GeneratedInput {
+        input: lines.join("\n"),
+        part1: Some(signal_strength.to_string()),
+        part2: Some(screen.iter().map(|row| row.iter().collect()).collect::<Vec<String>>().join("\n"))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day10::Day10, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day10), Ok(()), "seed {}", seed);
+        }
+    }
+}
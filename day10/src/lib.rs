@@ -0,0 +1,228 @@
+pub mod generator;
+
+pub mod day10 {
+    use std::{io::BufRead, collections::HashMap};
+    use aoc_common::{input::read_input, parse::{number_lines, Line, ParseError}, solution::Solution};
+
+    pub fn sum_of_signal_strengths(input: &mut dyn BufRead) -> Result<i32, ParseError> {
+        return Day10.part1(&Day10.parse(input)?);
+    }
+
+    pub fn render_image(input: &mut dyn BufRead) -> Result<Vec<String>, ParseError> {
+        return Ok(run_program(&Day10.parse(input)?).render_image());
+    }
+
+    pub struct Day10;
+
+    impl Solution for Day10 {
+        type Parsed = Vec<Instruction>;
+        type Answer1 = i32;
+        type Answer2 = String;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Instruction>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, instructions: &Vec<Instruction>) -> Result<i32, ParseError> {
+            let device = run_program(instructions);
+            return Ok(device.get_watched_values().iter().map(|(&cycle, &value)| cycle as i32 * value).sum());
+        }
+
+        fn part2(&self, instructions: &Vec<Instruction>) -> Result<String, ParseError> {
+            return Ok(run_program(instructions).render_image().join("\n"));
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Instruction {
+        Addx(i32),
+        Noop
+    }
+
+    /// Runs the program on a fresh device watching the signal at the puzzle's cycles.
+    pub fn run_program(instructions: &[Instruction]) -> HandlheldDevice {
+        let mut device = HandlheldDevice::new();
+        device.set_watches(vec![20, 60, 100, 140, 180, 220]);
+
+        for instruction in instructions.iter() {
+            device.execute(*instruction);
+        }
+
+        return device;
+    }
+    
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Instruction>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines).iter().map(parse_instruction).collect();
+    }
+
+    fn parse_instruction(line: &Line) -> Result<Instruction, ParseError> {
+        return match line.text.split_once(' ') {
+            Some(("addx", value)) => Ok(Instruction::Addx(line.parse::<i32>(value, "an integer to add")?)),
+            None if line.text == "noop" => Ok(Instruction::Noop),
+            _ => Err(line.error_at(1, "`noop` or `addx <value>`"))
+        };
+    }
+
+    pub struct HandlheldDevice {
+        clock: usize,
+        x_register: i32,
+        watches: Vec<usize>,
+        watched_values: HashMap<usize, i32>,
+        crt: Vec<Vec<char>>
+    }
+
+    impl Default for HandlheldDevice {
+        fn default() -> HandlheldDevice {
+            return HandlheldDevice::new();
+        }
+    }
+
+    impl HandlheldDevice {
+        pub fn new() -> HandlheldDevice {
+            return HandlheldDevice { clock: 0, x_register: 1, watches: Vec::new(), watched_values: HashMap::new(), crt: vec![vec![' '; 40]; 6]};
+        }
+
+        /// Cycles during which the value of the X register is recorded.
+        pub fn set_watches(&mut self, watches: Vec<usize>) {
+            self.watches = watches;
+        }
+
+        pub fn execute(&mut self, instruction: Instruction) {
+            match instruction {
+                Instruction::Addx(value) => self.addx(value),
+                Instruction::Noop => self.noop()
+            }
+        }
+
+        pub fn addx(&mut self, arg: i32) {
+            self.proceed_clock(2);
+            self.x_register += arg;
+        }
+
+        pub fn noop(&mut self) {
+            self.proceed_clock(1);
+        }
+
+        pub fn get_clock(&self) -> usize {
+            return self.clock;
+        }
+
+        pub fn get_x_register(&self) -> i32 {
+            return self.x_register;
+        }
+
+        fn proceed_clock(&mut self, cycles: usize) {
+            for _ in 0..cycles {
+                self.clock += 1;
+                self.watch_values();
+                self.draw_pixel();
+            }
+        }
+
+        fn watch_values(&mut self) {
+            if self.watches.iter().find(|w| **w == self.clock).is_some() {
+                self.watched_values.insert(self.clock, self.x_register);
+            }
+        }
+
+        fn draw_pixel(&mut self) {
+            if self.sprite_covers_current_pixel() {
+                self.draw_lit_pixel();
+            }
+            else {
+                self.draw_dark_pixel();
+            }
+        }
+
+        fn sprite_covers_current_pixel(&self) -> bool {
+            let sprite_position = self.x_register % 40;
+            let sprite = [sprite_position - 1, sprite_position, sprite_position + 1];
+            let current_pixel = self.get_current_pixel_coords().0 as i32;
+            return sprite.contains(&current_pixel);
+        }
+
+        fn draw_lit_pixel(&mut self) {
+            self.draw('#', self.get_current_pixel_coords());
+        }
+
+        fn draw_dark_pixel(&mut self) {
+            self.draw('.', self.get_current_pixel_coords());
+        }
+
+        fn get_current_pixel_coords(&self) -> (usize, usize) {
+            let y = (self.clock - 1) / 40;
+            let x = (self.clock - 1) % 40;
+            return (x, y);
+        }
+
+        fn draw(&mut self, pixel: char, coords: (usize, usize)) {
+            self.crt.get_mut(coords.1).unwrap()[coords.0] = pixel;
+        }
+
+        pub fn get_watched_values(&self) -> &HashMap<usize, i32> {
+            return &self.watched_values;
+        }
+
+        pub fn render_image(&self) -> Vec<String> {
+            return self.crt.iter().map(|r| r.iter().collect()).collect();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day10;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader, io::BufRead};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day10::sum_of_signal_strengths(&mut f).unwrap(), 13140);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day10::sum_of_signal_strengths(&mut f).unwrap(), 14060);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let rendered_image = day10::render_image(&mut f).unwrap();
+        let sample_image = BufReader::new(File::open("./sample.crt.out").unwrap()).lines().map(Result::unwrap).collect::<Vec<String>>();
+        
+        assert_eq!(rendered_image, sample_image);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        let rendered_image = day10::render_image(&mut f).unwrap();
+        
+        for row in rendered_image {
+            println!("{}", row);
+        }
+
+        // eight capital letters appearing on CRT: PAPKFKEJ
+    }
+
+    #[test]
+    fn reports_unknown_instruction() {
+        assert_eq!(day10::sum_of_signal_strengths(&mut "noop\nsubx 3".as_bytes()), Err(ParseError::new(2, 1, "`noop` or `addx <value>`")));
+        assert_eq!(day10::sum_of_signal_strengths(&mut "addx x".as_bytes()), Err(ParseError::new(1, 6, "an integer to add")));
+    }
+
+    #[test]
+    fn drives_device_through_library_api() {
+        let mut device = day10::HandlheldDevice::new();
+        device.set_watches(vec![2]);
+        device.execute(day10::Instruction::Noop);
+        device.execute(day10::Instruction::Addx(3));
+        assert_eq!((device.get_clock(), device.get_x_register()), (3, 4));
+        assert_eq!(device.get_watched_values().get(&2), Some(&1));
+        assert!(device.render_image()[0].starts_with("###"));
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub min_monkeys: i64,
+    pub max_monkeys: i64,
+    pub max_items_per_monkey: i64,
+    pub max_worry_level: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { min_monkeys: 2, max_monkeys: 8, max_items_per_monkey: 4, max_worry_level: 99 };
+    }
+}
+
+const PRIMES: [u128; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(u128),
+    Mul(u128),
+    Square
+}
+
+impl Operation {
+    fn apply(&self, value: u128) -> Option<u128> {
+        return match self {
+            Operation::Add(operand) => value.checked_add(*operand),
+            Operation::Mul(operand) => value.checked_mul(*operand),
+            Operation::Square => value.checked_mul(value)
+        };
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<u128>,
+    operation: Operation,
+    divisible_by: u128,
+    if_true: usize,
+    if_false: usize
+}
+
+/// Notes on between `min_monkeys` and `max_monkeys` monkeys (at most 12) with distinct
+/// prime divisors. The answers are for `Day11::default()`; candidates whose worry levels
+/// would overflow during the first part are discarded and drawn again.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    loop {
+        let monkeys = generate_monkeys(&mut rng, params);
+        if let Some(part1) = play(&monkeys, 20, |worry_level| Some(worry_level / 3)) {
+            let modulus = monkeys.iter().map(|m| m.divisible_by).product::<u128>();
+            let part2 = play(&monkeys, 10000, |worry_level| Some(worry_level % modulus)).unwrap();
+            return GeneratedInput {
+                input: monkeys.iter().enumerate().map(|(id, monkey)| describe(id, monkey)).collect::<Vec<String>>().join("\n\n"),
+                part1: Some(part1.to_string()),
+                part2: Some(part2.to_string())
+            };
+        }
+    }
+}
+
+fn generate_monkeys(rng: &mut Rng, params: &Params) -> Vec<Monkey> {
+    let count = rng.between(params.min_monkeys, params.max_monkeys) as usize;
+    let mut divisors = PRIMES.to_vec();
+    rng.shuffle(&mut divisors);
+
+    return (0..count)
+        .map(|id| Monkey {
+            items: (0..rng.between(0, params.max_items_per_monkey)).map(|_| rng.between(1, params.max_worry_level) as u128).collect(),
+            operation: match rng.below(8) {
+                0 => Operation::Square,
+                1..=3 => Operation::Add(rng.between(1, 9) as u128),
+                _ => Operation::Mul(rng.between(2, 19) as u128)
+            },
+            divisible_by: divisors[id],
+            if_true: (id + 1 + rng.index(count - 1)) % count,
+            if_false: (id + 1 + rng.index(count - 1)) % count
+        })
+        .collect();
+}
+
+fn describe(id: usize, monkey: &Monkey) -> String {
+    let operation = match monkey.operation {
+        Operation::Add(operand) => format!("+ {}", operand),
+        Operation::Mul(operand) => format!("* {}", operand),
+        Operation::Square => "* old".to_string()
+    };
+    return [
+        format!("Monkey {}:", id),
+        format!("  Starting items: {}", monkey.items.iter().map(|i| i.to_string()).collect::<Vec<String>>().join(", ")),
+        format!("  Operation: new = old {}", operation),
+        format!("  Test: divisible by {}", monkey.divisible_by),
+        format!("    If true: throw to monkey {}", monkey.if_true),
+        format!("    If false: throw to monkey {}", monkey.if_false)
+    ].join("\n");
+}
+
+/// Level of monkey business, or `None` if a worry level overflows.
+fn play(monkeys: &[Monkey], rounds: usize, relieve: impl Fn(u128) -> Option<u128>) -> Option<u128> {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspections = vec![0u128; monkeys.len()];
+    for _ in 0..rounds {
+        for id in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[id].items) {
+                let worry_level = relieve(monkeys[id].operation.apply(item)?)?;
+                let target = if worry_level % monkeys[id].divisible_by == 0 { monkeys[id].if_true } else { monkeys[id].if_false };
+                monkeys[target].items.push(worry_level);
+                inspections[id] += 1;
+            }
+        }
+    }
+    inspections.sort_unstable();
+    return Some(inspections.iter().rev().take(2).product());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day11::Day11, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day11::default()), Ok(()), "seed {}", seed);
+        }
+    }
+}
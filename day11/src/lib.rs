@@ -0,0 +1,382 @@
+pub mod generator;
+
+pub mod day11 {
+    use std::{io::BufRead, collections::HashMap};
+    use regex::Regex;
+    use tracing::{debug, trace};
+    use aoc_common::{input::{read_input, split_sections}, parse::{number_lines, unexpected_end, Line, ParseError}, solution::Solution};
+
+    pub fn level_of_monkey_business_after_rounds(input: &mut dyn BufRead, rounds: usize, relief: Option<u128>) -> Result<u128, ParseError> {
+        let monkeys = parse_input(input)?;
+        return Ok(play_game(&monkeys, rounds, relief));
+    }
+
+    /// Level of monkey business after playing `rounds` rounds, dividing worry levels by `relief` after each inspection if given.
+    pub fn play_game(monkeys: &[Monkey], rounds: usize, relief: Option<u128>) -> u128 {
+        let mut game = Game::new(monkeys, relief);
+
+        for round in 1..=rounds {
+            game.play_round();
+            trace!(round, inspections = ?game.get_inspections(), "round played");
+        }
+
+        debug!(rounds, inspections = ?game.get_inspections(), "game over");
+        return game.level_of_monkey_business();
+    }
+
+    pub struct Day11 {
+        pub part1_rounds: usize,
+        pub part1_relief: Option<u128>,
+        pub part2_rounds: usize,
+        pub part2_relief: Option<u128>
+    }
+
+    impl Default for Day11 {
+        fn default() -> Day11 {
+            return Day11 { part1_rounds: 20, part1_relief: Some(3), part2_rounds: 10000, part2_relief: None };
+        }
+    }
+
+    impl Solution for Day11 {
+        type Parsed = Vec<Monkey>;
+        type Answer1 = u128;
+        type Answer2 = u128;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Monkey>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, monkeys: &Vec<Monkey>) -> Result<u128, ParseError> {
+            return Ok(play_game(monkeys, self.part1_rounds, self.part1_relief));
+        }
+
+        fn part2(&self, monkeys: &Vec<Monkey>) -> Result<u128, ParseError> {
+            return Ok(play_game(monkeys, self.part2_rounds, self.part2_relief));
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Monkey>, ParseError> {
+        let lines = read_input(input)?;
+        let numbered_lines = number_lines(&lines);
+        let sections = split_sections(&numbered_lines);
+        if sections.len() < 2 {
+            return Err(unexpected_end(numbered_lines.last(), "a blank line followed by a second monkey"));
+        }
+        return sections.iter().enumerate()
+            .map(|(index, monkey)| parse_monkey(monkey, index, sections.len()))
+            .collect();
+    }
+
+    /// Monkeys are numbered by their position in the input, and throw to other monkeys only.
+    fn parse_monkey(lines: &[Line], index: usize, monkey_count: usize) -> Result<Monkey, ParseError> {
+        let line = |i: usize, expected: &str| lines.get(i).ok_or_else(|| unexpected_end(lines.last(), expected));
+        let monkey_id = parse_monkey_id(line(0, "`Monkey <id>:`")?)?;
+        let items = parse_starting_items(line(1, "`  Starting items: <worry levels>`")?, monkey_id)?;
+        let (operator, operand) = parse_operation(line(2, "`  Operation: new = old <operator> <operand>`")?)?;
+        let divisible_by = parse_divisible_by(line(3, "`  Test: divisible by <number>`")?)?;
+        let monkey_to_throw_if_true = parse_monkey_to_throw_to(line(4, "`    If true: throw to monkey <id>`")?, "true", index, monkey_count)?;
+        let monkey_to_throw_if_false = parse_monkey_to_throw_to(line(5, "`    If false: throw to monkey <id>`")?, "false", index, monkey_count)?;
+        return Ok(Monkey { items, operator, operand, divisible_by, monkey_to_throw_if_true, monkey_to_throw_if_false, inspected_items: 0, relief: None, cache: HashMap::new() });
+    }
+
+    fn capture<'a>(line: &Line<'a>, pattern: &str, expected: &str) -> Result<Vec<&'a str>, ParseError> {
+        let re = Regex::new(pattern).unwrap();
+        return re.captures(line.text)
+            .map(|captures| captures.iter().skip(1).map(|c| c.map(|c| c.as_str()).unwrap_or("")).collect())
+            .ok_or_else(|| line.error_at(1, expected));
+    }
+
+    fn parse_monkey_id(line: &Line) -> Result<usize, ParseError> {
+        let captures = capture(line, r"^Monkey (\d+):$", "`Monkey <id>:`")?;
+        return line.parse::<usize>(captures[0], "a monkey id");
+    }
+
+    fn parse_starting_items(line: &Line, monkey_id: usize) -> Result<Vec<Item>, ParseError> {
+        let captures = capture(line, r"^  Starting items: (.*)$", "`  Starting items: <worry levels>`")?;
+        let items = if captures[0].is_empty() {
+            Vec::new()
+        }
+        else {
+            captures[0].split(", ")
+                .map(|i| line.parse::<u128>(i, "a worry level"))
+                .collect::<Result<Vec<u128>, ParseError>>()?
+        };
+        return Ok(items.iter().enumerate()
+            .map(|(i, v)| Item::new(format!("{}_{}", monkey_id, i), *v))
+            .collect());
+    }
+
+    fn parse_operation(line: &Line) -> Result<(Operator, Option<u128>), ParseError> {
+        let captures = capture(line, r"^  Operation: new = old (\S+) (\S+)$", "`  Operation: new = old <operator> <operand>`")?;
+
+        let operator = match captures[0] {
+            "+" => Operator::ADD,
+            "*" => Operator::MUL,
+            _ => return Err(line.error_on(captures[0], "`+` or `*`"))
+        };
+
+        let operand = if captures[1].eq("old") {
+            Option::None
+        }
+        else {
+            Some(line.parse::<u128>(captures[1], "`old` or a number")?)
+        };
+
+        return Ok((operator, operand));
+    }
+
+    fn parse_divisible_by(line: &Line) -> Result<u128, ParseError> {
+        let captures = capture(line, r"^  Test: divisible by (\d+)$", "`  Test: divisible by <number>`")?;
+        let divisible_by = line.parse::<u128>(captures[0], "a divisor")?;
+        if divisible_by == 0 {
+            return Err(line.error_on(captures[0], "a non-zero divisor"));
+        }
+        return Ok(divisible_by);
+    }
+
+    fn parse_monkey_to_throw_to(line: &Line, condition: &str, index: usize, monkey_count: usize) -> Result<usize, ParseError> {
+        let expected = format!("`    If {}: throw to monkey <id>`", condition);
+        let captures = capture(line, &format!(r"^    If {}: throw to monkey (\d+)$", condition), &expected)?;
+        let expected_monkey = format!("a monkey id from 0 to {}", monkey_count - 1);
+        let monkey = line.parse::<usize>(captures[0], &expected_monkey)?;
+        if monkey >= monkey_count {
+            return Err(line.error_on(captures[0], expected_monkey));
+        }
+        if monkey == index {
+            return Err(line.error_on(captures[0], format!("a monkey other than {} itself", index)));
+        }
+        return Ok(monkey);
+    }
+
+    #[derive(Clone)]
+    pub struct Monkey {
+        items: Vec<Item>,
+        operator: Operator,
+        operand: Option<u128>,
+        divisible_by: u128,
+        monkey_to_throw_if_true: usize,
+        monkey_to_throw_if_false: usize,
+        inspected_items: u128,
+        relief: Option<u128>,
+        cache: HashMap<String, (usize, u128)>
+    }
+
+    #[derive(Clone)]
+    struct Item {
+        id: String,
+        initial_value: u128,
+        operations: Vec<(Operator, Option<u128>)>
+    }
+
+    impl Item {
+        fn new(id: String, initial_value: u128) -> Item {
+            Item { id, initial_value, operations: Vec::new() }
+        }
+
+        fn add_operation(&mut self, operator: Operator, operand: Option<u128>) {
+            self.operations.push((operator, operand));
+        }
+
+        fn add_relief(&mut self, relief: u128) {
+            self.operations.push((Operator::DIV, Some(relief)));
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    enum Operator {
+        ADD,
+        MUL,
+        DIV
+    }
+
+    impl Monkey {
+        pub fn get_number_of_items(&self) -> usize {
+            return self.items.len();
+        }
+
+        pub fn get_inspected_items(&self) -> u128 {
+            return self.inspected_items;
+        }
+
+        pub fn get_divisible_by(&self) -> u128 {
+            return self.divisible_by;
+        }
+
+        /// Monkeys that items are thrown to when the test passes and when it fails.
+        pub fn get_monkeys_to_throw_to(&self) -> (usize, usize) {
+            return (self.monkey_to_throw_if_true, self.monkey_to_throw_if_false);
+        }
+
+        fn inspect_next_item(&mut self) -> (Item, usize) {
+            let mut item = self.items.remove(0);
+
+            item.add_operation(self.operator, self.operand);
+            if let Some(r) = self.relief {
+                item.add_relief(r);
+            }
+
+            let monkey_to_throw_to = if self.passes_test(&item) {
+                 self.monkey_to_throw_if_true
+            }
+            else {
+                self.monkey_to_throw_if_false
+            };
+
+            self.inspected_items += 1;
+
+            return (item, monkey_to_throw_to);
+        }
+
+        fn passes_test(&mut self, item: &Item) -> bool {
+            let optimiser: Option<u128> = if self.relief.is_none() { Some(self.divisible_by) } else { None };
+            let worry_level = self.perform_operations(item, optimiser);
+            return worry_level.is_multiple_of(self.divisible_by);
+        }
+
+        fn perform_operations(&mut self, item: &Item, optimiser: Option<u128>) -> u128 {
+            let (offset, mut value) = self.get_cached_item_value(item);
+
+            for (operator, operand) in item.operations.iter().skip(offset) {
+                let operand = operand.unwrap_or(value);
+                value = self.perform_operation(optimiser.map(|d| value % d).unwrap_or(value), operator, optimiser.map(|d| operand % d).unwrap_or(operand));
+            };
+
+            self.cache_item_value(item, value);
+
+            return value;
+        }
+
+        fn get_cached_item_value(&mut self, item: &Item) -> (usize, u128) {
+            let cached_value = self.cache.remove(&item.id);
+            return cached_value.unwrap_or((0, item.initial_value));
+        }
+
+        fn perform_operation(&self, value: u128, operator: &Operator, operand: u128) -> u128 {
+            return match operator {
+                Operator::ADD => value + operand,
+                Operator::MUL => value * operand,
+                Operator::DIV if operand == 0 => 0,
+                Operator::DIV => value / operand
+            };
+        }
+
+        fn cache_item_value(&mut self, item: &Item, worry_level: u128) {
+            self.cache.insert(String::from(&item.id), (item.operations.len(), worry_level));
+        }
+    }
+
+    pub struct Game {
+        monkeys: Vec<Monkey>
+    }
+
+    impl Game {
+        pub fn new(monkeys: &[Monkey], relief: Option<u128>) -> Game {
+            let monkeys = monkeys.iter()
+                .map(|monkey| Monkey { relief, ..monkey.clone() })
+                .collect();
+            return Game { monkeys };
+        }
+
+        pub fn get_monkeys(&self) -> &[Monkey] {
+            return &self.monkeys;
+        }
+
+        pub fn play_round(&mut self) {
+            for monkey_id in 0..self.monkeys.len() {
+                self.make_turn(monkey_id);
+            }
+        }
+
+        fn make_turn(&mut self, monkey_id: usize) {
+            for _ in 0..self.monkeys.get(monkey_id).unwrap().items.len() {
+                let (inspected_item, destination_monkey) = self.monkeys.get_mut(monkey_id).map(|m| m.inspect_next_item()).unwrap();
+                self.throw_item_to_monkey(inspected_item, destination_monkey);
+            }
+        }
+
+        fn throw_item_to_monkey(&mut self, item: Item, destination_monkey: usize) {
+            self.monkeys.get_mut(destination_monkey).unwrap().items.push(item);
+        }
+
+        /// Number of items inspected so far by each monkey.
+        pub fn get_inspections(&self) -> Vec<u128> {
+            return self.monkeys.iter().map(|m| m.inspected_items).collect();
+        }
+
+        /// Product of the two highest numbers of inspected items.
+        pub fn level_of_monkey_business(&self) -> u128 {
+            let mut inspections = self.get_inspections();
+            inspections.sort();
+            inspections.reverse();
+            return inspections[0..2].iter().product();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day11;
+    use aoc_common::solution::Solution;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut f, 20, Some(3)).unwrap(), 10605);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut f, 20, Some(3)).unwrap(), 72884);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut f, 10000, None).unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut f, 10000, None).unwrap(), 15310845153);
+    }
+
+    #[test]
+    fn reports_malformed_monkey() {
+        let monkey = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old - 19\n  Test: divisible by 23\n    If true: throw to monkey 1\n    If false: throw to monkey 1";
+        let other = "Monkey 1:\n  Starting items: 54\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 0\n    If false: throw to monkey 0";
+        let input = format!("{}\n\n{}", monkey, other);
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut input.as_bytes(), 20, Some(3)), Err(ParseError::new(3, 24, "`+` or `*`")));
+        let truncated = "Monkey 0:\n  Starting items: 79, 98\n\nMonkey 1:";
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut truncated.as_bytes(), 20, Some(3)), Err(ParseError::new(3, 1, "`  Operation: new = old <operator> <operand>`")));
+    }
+
+    #[test]
+    fn reports_games_without_two_monkeys_throwing_to_each_other() {
+        let monkey = "Monkey 0:\n  Starting items: 79\n  Operation: new = old * 19\n  Test: divisible by 23\n    If true: throw to monkey 1\n    If false: throw to monkey 0";
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut monkey.as_bytes(), 20, Some(3)), Err(ParseError::new(7, 1, "a blank line followed by a second monkey")));
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut "".as_bytes(), 20, Some(3)), Err(ParseError::new(1, 1, "a blank line followed by a second monkey")));
+
+        let other = "Monkey 1:\n  Starting items: 54\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 0\n    If false: throw to monkey 2";
+        let input = format!("{}\n\n{}", monkey, other);
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut input.as_bytes(), 20, Some(3)), Err(ParseError::new(6, 31, "a monkey other than 0 itself")));
+        let input = format!("{}\n\n{}", monkey.replace("monkey 0", "monkey 1"), other);
+        assert_eq!(day11::level_of_monkey_business_after_rounds(&mut input.as_bytes(), 20, Some(3)), Err(ParseError::new(13, 31, "a monkey id from 0 to 1")));
+    }
+
+    #[test]
+    fn plays_rounds_through_library_api() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        let monkeys = day11::Day11::default().parse(&mut f).unwrap();
+        assert_eq!(monkeys[0].get_monkeys_to_throw_to(), (2, 3));
+
+        let mut game = day11::Game::new(&monkeys, Some(3));
+        game.play_round();
+        let items = game.get_monkeys().iter().map(|m| m.get_number_of_items()).collect::<Vec<usize>>();
+        assert_eq!(items, vec![4, 6, 0, 0]);
+        assert_eq!(game.get_monkeys()[0].get_inspected_items(), 2);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub width: usize,
+    pub height: usize,
+    pub trail_length: usize
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { width: 24, height: 12, trail_length: 40 };
+    }
+}
+
+/// A `width` by `height` heightmap (at least 26 squares) whose elevations mostly rise from
+/// the left and top edges, with a climbable trail of `trail_length` squares from `S` to `E`.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let mut elevations = vec![vec![0i64; params.width]; params.height];
+    for y in 0..params.height {
+        for x in 0..params.width {
+            let left = if x > 0 { elevations[y][x - 1] } else { 0 };
+            let up = if y > 0 { elevations[y - 1][x] } else { 0 };
+            elevations[y][x] = ((left + up) / 2 + rng.between(-1, 2)).clamp(0, 25);
+        }
+    }
+
+    let trail = walk_trail(&mut rng, params);
+    for (i, (x, y)) in trail.iter().enumerate() {
+        elevations[*y][*x] = (i * 25 / (trail.len() - 1)) as i64;
+    }
+    let (start, end) = (trail[0], trail[trail.len() - 1]);
+
+    let input = (0..params.height)
+        .map(|y| (0..params.width)
+            .map(|x| match (x, y) {
+                p if p == start => 'S',
+                p if p == end => 'E',
+                _ => (b'a' + elevations[y][x] as u8) as char
+            })
+            .collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let distances = distances_to(&elevations, end);
+    let lowest = (0..params.height)
+        .flat_map(|y| (0..params.width).map(move |x| (x, y)))
+        .filter(|(x, y)| elevations[*y][*x] == 0)
+        .map(|(x, y)| distances[y][x])
+        .min();
+
+    return GeneratedInput {
+        input,
+        part1: Some(distances[start.1][start.0].to_string()),
+        part2: Some(lowest.unwrap_or(usize::MAX).to_string())
+    };
+}
+
+/// Loop-erased random walk, so that no square is visited twice.
+fn walk_trail(rng: &mut Rng, params: &Params) -> Vec<(usize, usize)> {
+    let length = params.trail_length.clamp(26, params.width * params.height);
+    let mut trail = vec![(rng.index(params.width), rng.index(params.height))];
+    while trail.len() < length {
+        let (x, y) = trail[trail.len() - 1];
+        let (dx, dy) = *rng.choose(&[(-1, 0), (1, 0), (0, -1), (0, 1)]);
+        let next = (x as i64 + dx, y as i64 + dy);
+        if next.0 < 0 || next.0 >= params.width as i64 || next.1 < 0 || next.1 >= params.height as i64 {
+            continue;
+        }
+        let next = (next.0 as usize, next.1 as usize);
+        match trail.iter().position(|square| *square == next) {
+            Some(index) => trail.truncate(index + 1),
+            None => trail.push(next)
+        }
+    }
+    return trail;
+}
+
+/// Breadth-first search backwards from `end`, stepping down at most one level at a time.
+fn distances_to(elevations: &[Vec<i64>], end: (usize, usize)) -> Vec<Vec<usize>> {
+    let (width, height) = (elevations[0].len(), elevations.len());
+    let mut distances = vec![vec![usize::MAX; width]; height];
+    distances[end.1][end.0] = 0;
+    let mut queue = VecDeque::from([end]);
+    while let Some((x, y)) = queue.pop_front() {
+        let neighbours = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbours.into_iter().filter(|(nx, ny)| *nx < width && *ny < height) {
+            if distances[ny][nx] == usize::MAX && elevations[ny][nx] + 1 >= elevations[y][x] {
+                distances[ny][nx] = distances[y][x] + 1;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    return distances;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day12::Day12, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day12), Ok(()), "seed {}", seed);
+        }
+    }
+}
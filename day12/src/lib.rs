@@ -0,0 +1,275 @@
+pub mod generator;
+
+pub mod day12 {
+    use std::{io::BufRead, collections::{HashMap, HashSet}};
+    use aoc_common::{geometry::Point, grid::{parse_grid, Grid}, input::read_input, parse::{number_lines, unexpected_end, ParseError}, render::{Colour, Render}, solution::Solution};
+
+    const EDGE_COST: usize = 1;
+
+    pub fn fewest_steps_required_to_reach_best_signal_location_from_current_position(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day12.part1(&Day12.parse(input)?);
+    }
+
+    pub fn fewest_steps_required_to_reach_best_signal_location_from_any_square_at_elevation_a(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day12.part2(&Day12.parse(input)?);
+    }
+
+    /// The heightmap, shaded from dark (elevation `a`) to light (`z`), with `S` in green and `E` in red.
+    pub fn picture(input: &mut dyn BufRead) -> Result<Box<dyn Render>, ParseError> {
+        return Ok(Box::new(Day12.parse(input)?));
+    }
+
+    pub struct Day12;
+
+    impl Solution for Day12 {
+        type Parsed = HeightMap;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<HeightMap, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, raw_grid: &HeightMap) -> Result<usize, ParseError> {
+            let digital_grid = raw_grid.digitize();
+            let current_coords = raw_grid.get_current_position_coords();
+            let best_signal_coords = raw_grid.get_best_signal_position_coords();
+
+            let mut navigation = Navigation::new(digital_grid);
+            navigation.analyze_routes(best_signal_coords);
+
+            return Ok(navigation.find_shortest_path_length(&current_coords));
+        }
+
+        fn part2(&self, raw_grid: &HeightMap) -> Result<usize, ParseError> {
+            let digital_grid = raw_grid.digitize();
+            let elevation_a = raw_grid.digitize_position('a');
+            let coords_at_elevation_a = digital_grid.find_coords(|e| *e == elevation_a);
+            let best_signal_coords = raw_grid.get_best_signal_position_coords();
+
+            let mut navigation = Navigation::new(digital_grid);
+            navigation.analyze_routes(best_signal_coords);
+
+            return Ok(coords_at_elevation_a.iter()
+                .map(|c| navigation.find_shortest_path_length(c))
+                .min().unwrap_or(usize::MAX));
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<HeightMap, ParseError> {
+        let lines = read_input(input)?;
+        let numbered_lines = number_lines(&lines);
+        let grid = parse_grid(&numbered_lines, "an elevation from `a` to `z`, `S` or `E`", |c| match c {
+            'a'..='z' | 'S' | 'E' => Some(c),
+            _ => None
+        })?;
+        for (position, name) in [('S', "the current position `S`"), ('E', "the best signal location `E`")] {
+            if grid.find_coords(|p| *p == position).is_empty() {
+                return Err(unexpected_end(numbered_lines.last(), name));
+            }
+        }
+        return Ok(HeightMap::new(grid));
+    }
+
+    pub struct HeightMap {
+        grid: Grid<char>
+    }
+
+    impl HeightMap {
+        /// A map of elevations `a` to `z`, which must mark the current position with `S` and the best signal location with `E`.
+        pub fn new(grid: Grid<char>) -> HeightMap {
+            return HeightMap { grid };
+        }
+
+        pub fn get_current_position_coords(&self) -> Point<usize> {
+            return self.find_position_coords('S');
+        }
+    
+        pub fn get_best_signal_position_coords(&self) -> Point<usize> {
+            return self.find_position_coords('E');
+        }
+    
+        fn find_position_coords(&self, position: char) -> Point<usize> {
+            return *self.grid.find_coords(|p| *p == position).first().unwrap();
+        }
+
+        /// Elevations as numbers, `a` being 0.
+        pub fn digitize(&self) -> Grid<usize> {
+            return self.grid.map(|p| self.digitize_position(*p));
+        }
+
+        pub fn digitize_position(&self, position: char) -> usize {
+            let unified_position = match position {
+                'S' => 'a',
+                'E' => 'z',
+                _ => position
+            };
+            return unified_position as usize - 'a' as usize;
+        }
+    }
+
+    const LOWEST_ELEVATION: Colour = Colour::rgb(40, 30, 90);
+    const HIGHEST_ELEVATION: Colour = Colour::rgb(240, 240, 250);
+
+    impl Render for HeightMap {
+        fn width(&self) -> usize {
+            return self.grid.width();
+        }
+
+        fn height(&self) -> usize {
+            return self.grid.height();
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            return match *self.grid.get(x, y) {
+                'S' => Colour::rgb(30, 200, 60),
+                'E' => Colour::rgb(220, 30, 30),
+                elevation => LOWEST_ELEVATION.mix(&HIGHEST_ELEVATION, self.digitize_position(elevation), 25)
+            };
+        }
+    }
+
+    /// Shortest routes over a digitized height map, climbing at most one level per step.
+    pub struct Navigation {
+        grid: Grid<usize>,
+        graph: HashMap<Point<usize>, HashSet<Point<usize>>>,
+        distances: HashMap<Point<usize>, usize>
+    }
+
+    impl Navigation {
+        pub fn new(grid: Grid<usize>) -> Navigation {
+            return Navigation { graph: GraphBuilder::new(&grid).build(), grid, distances: HashMap::new() };
+        }
+
+        /// Computes the length of the shortest route from every square to `to`.
+        pub fn analyze_routes(&mut self, to: Point<usize>) {
+            let mut predecessors: HashMap<Point<usize>, Point<usize>> = HashMap::new();
+            let mut to_check = self.grid.coords().into_iter().collect::<HashSet<Point<usize>>>();
+
+            self.distances.clear();
+            self.distances.insert(to, 0);
+
+            for _ in 0..to_check.len() {
+                let mut nodes: Vec<(Point<usize>, &usize)> = to_check.iter()
+                    .filter(|q| self.distances.contains_key(q))
+                    .map(|q| (*q, self.distances.get(q).unwrap()))
+                    .collect();
+                nodes.sort_by(|a, b| a.1.cmp(b.1));
+                
+                if let Some(nearest_node) = nodes.first().map(|q| q.0) {
+                    to_check.remove(&nearest_node);
+
+                    for neighbour in self.graph.get(&nearest_node).unwrap() {
+                        let existing_distance = self.distances.get(neighbour);
+                        let candidate_distance = *self.distances.get(&nearest_node).unwrap();
+                        if existing_distance.map(|d| *d > candidate_distance + EDGE_COST).unwrap_or(true) {
+                            self.distances.insert(*neighbour, candidate_distance + EDGE_COST);
+                            predecessors.insert(*neighbour, nearest_node);
+                        }
+                    }
+                }
+            }
+        }
+        
+        /// Length of the shortest route from `from`, as analyzed, or `usize::MAX` if `to` is unreachable.
+        pub fn find_shortest_path_length(&self, from: &Point<usize>) -> usize {
+            return self.distances.get(from).copied().unwrap_or(usize::MAX);
+        }
+    }
+
+    struct GraphBuilder<'a> {
+        grid: &'a Grid<usize>
+    }
+
+    impl GraphBuilder<'_> {
+        fn new(grid: &Grid<usize>) -> GraphBuilder<'_> {
+            return GraphBuilder { grid }
+        }
+
+        fn build(&self) -> HashMap<Point<usize>, HashSet<Point<usize>>> {
+            let mut graph: HashMap<Point<usize>, HashSet<Point<usize>>> = HashMap::new();
+            
+            for src in self.grid.coords() {
+                let neighbours = graph.entry(src).or_default();
+                for neighbour in self.get_reachable_neighbours(src) {
+                    neighbours.insert(neighbour);
+                }
+            }
+
+            return graph;
+        }
+
+        fn get_reachable_neighbours(&self, dst: Point<usize>) -> Vec<Point<usize>> {
+            return self.grid.neighbours(&dst).into_iter()
+                .filter(|src| self.is_reachable(*src, dst))
+                .collect();
+        }
+
+        fn is_reachable(&self, source: Point<usize>, destination: Point<usize>) -> bool {
+            let src_position = *self.grid.get_at(&source) as isize;
+            let dst_position = *self.grid.get_at(&destination) as isize;
+            return src_position == dst_position - 1 || src_position >= dst_position;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day12;
+    use aoc_common::{geometry::Point, grid::Grid, render::Colour};
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_current_position(&mut f).unwrap(), 31);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_current_position(&mut f).unwrap(), 423);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_any_square_at_elevation_a(&mut f).unwrap(), 29);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_any_square_at_elevation_a(&mut f).unwrap(), 416);
+    }
+
+    #[test]
+    fn reports_invalid_elevation_or_missing_position() {
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_current_position(&mut "Sab\nc1E".as_bytes()), Err(ParseError::new(2, 2, "an elevation from `a` to `z`, `S` or `E`")));
+        assert_eq!(day12::fewest_steps_required_to_reach_best_signal_location_from_current_position(&mut "Sab\ncde".as_bytes()), Err(ParseError::new(3, 1, "the best signal location `E`")));
+    }
+
+    #[test]
+    fn navigates_through_library_api() {
+        let map = day12::HeightMap::new(Grid::new(vec![vec!['S', 'b', 'c'], vec!['a', 'z', 'E']]));
+        assert_eq!(map.get_current_position_coords(), Point::new(0, 0));
+        assert_eq!(map.digitize_position('E'), 25);
+
+        let mut navigation = day12::Navigation::new(map.digitize());
+        navigation.analyze_routes(Point::new(1, 1));
+        assert_eq!(navigation.find_shortest_path_length(&Point::new(0, 0)), usize::MAX);
+        navigation.analyze_routes(Point::new(2, 0));
+        assert_eq!(navigation.find_shortest_path_length(&Point::new(0, 0)), 2);
+    }
+
+    #[test]
+    fn renders_elevations() {
+        let picture = day12::picture(&mut "Sbz\naaE".as_bytes()).unwrap();
+        assert_eq!((picture.width(), picture.height()), (3, 2));
+        assert_eq!(picture.colour(0, 0), Colour::rgb(30, 200, 60));
+        assert_eq!(picture.colour(2, 1), Colour::rgb(220, 30, 30));
+        assert_eq!(picture.colour(0, 1), Colour::rgb(40, 30, 90));
+        assert_eq!(picture.colour(2, 0), Colour::rgb(240, 240, 250));
+    }
+}
@@ -0,0 +1,125 @@
+use std::cmp::Ordering;
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub pairs: usize,
+    pub max_depth: usize,
+    pub max_length: i64,
+    pub max_value: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { pairs: 150, max_depth: 4, max_length: 5, max_value: 10 };
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Value {
+    Integer(u32),
+    List(Vec<Value>)
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Value) -> Ordering {
+        return match (self, other) {
+            (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+            (Value::List(left), Value::List(right)) => left.cmp(right),
+            (Value::Integer(_), Value::List(right)) => [self.clone()][..].cmp(&right[..]),
+            (Value::List(left), Value::Integer(_)) => left[..].cmp(&[other.clone()][..])
+        };
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Value {
+    fn render(&self) -> String {
+        return match self {
+            Value::Integer(value) => value.to_string(),
+            Value::List(values) => format!("[{}]", values.iter().map(Value::render).collect::<Vec<String>>().join(","))
+        };
+    }
+}
+
+/// `pairs` pairs of random nested packets. Right packets often share a prefix with
+/// the left one, so that comparisons have to look past the first element.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let pairs = (0..params.pairs)
+        .map(|_| {
+            let left = generate_list(&mut rng, params, 0);
+            let right = if rng.chance(1, 2) { mutate(&mut rng, params, &left, 0) } else { generate_list(&mut rng, params, 0) };
+            return (left, right);
+        })
+        .collect::<Vec<(Value, Value)>>();
+
+    let input = pairs.iter()
+        .map(|(left, right)| format!("{}\n{}", left.render(), right.render()))
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    let sum_of_indices = pairs.iter().zip(1..)
+        .filter(|((left, right), _)| left < right)
+        .map(|(_, index)| index)
+        .sum::<usize>();
+
+    let divider = |value| Value::List(vec![Value::List(vec![Value::Integer(value)])]);
+    let packets = pairs.iter().flat_map(|(left, right)| [left, right]).collect::<Vec<&Value>>();
+    let position_of = |divider: &Value| packets.iter().filter(|packet| **packet <= divider).count() + 1;
+    let decoder_key = position_of(&divider(2)) * (position_of(&divider(6)) + 1);
+
+    return GeneratedInput {
+        input,
+        part1: Some(sum_of_indices.to_string()),
+        part2: Some(decoder_key.to_string())
+    };
+}
+
+fn generate_value(rng: &mut Rng, params: &Params, depth: usize) -> Value {
+    if depth < params.max_depth && rng.chance(1, 3) {
+        return generate_list(rng, params, depth + 1);
+    }
+    return Value::Integer(rng.between(0, params.max_value) as u32);
+}
+
+fn generate_list(rng: &mut Rng, params: &Params, depth: usize) -> Value {
+    return Value::List((0..rng.between(0, params.max_length)).map(|_| generate_value(rng, params, depth)).collect());
+}
+
+/// Copy of `value` with one random change somewhere inside, still a list at the top level.
+fn mutate(rng: &mut Rng, params: &Params, value: &Value, depth: usize) -> Value {
+    return match value {
+        Value::List(values) if !values.is_empty() && rng.chance(2, 3) => {
+            let mut values = values.clone();
+            let index = rng.index(values.len());
+            values[index] = mutate(rng, params, &values[index], depth + 1);
+            Value::List(values)
+        },
+        Value::List(values) if rng.chance(1, 2) => {
+            let mut values = values.clone();
+            values.truncate(rng.index(values.len() + 1));
+            Value::List(values)
+        },
+        Value::Integer(integer) if rng.chance(1, 3) => Value::List(vec![Value::Integer(*integer)]),
+        _ if depth == 0 => generate_list(rng, params, depth),
+        _ => generate_value(rng, params, depth)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day13::Day13, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day13), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+pub mod generator;
+
+pub mod day13 {
+    use std::{io::BufRead, cmp::Ordering};
+    use aoc_common::{input::{read_input, split_sections}, parse::{number_lines, unexpected_end, Line, ParseError}, solution::Solution};
+    use json::{JsonValue, number::Number};
+
+    pub fn sum_of_pair_indices_in_the_right_order(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day13.part1(&Day13.parse(input)?);
+    }
+
+    pub fn decoder_key_for_the_distress_signal(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day13.part2(&Day13.parse(input)?);
+    }
+
+    pub struct Day13;
+
+    impl Solution for Day13 {
+        type Parsed = Vec<Packet>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Packet>, ParseError> {
+            return parse_input_as_packets(input);
+        }
+
+        fn part1(&self, packets: &Vec<Packet>) -> Result<usize, ParseError> {
+            return Ok(packets.chunks(2)
+                .map(|pair| is_packet_pair_in_right_order(&pair[0], &pair[1]))
+                .zip(1..)
+                .filter(|(in_right_order, _)| *in_right_order)
+                .map(|(_, index)| index)
+                .sum());
+        }
+
+        fn part2(&self, packets: &Vec<Packet>) -> Result<usize, ParseError> {
+            let mut packets = packets.iter()
+                .map(|p| Packet { data: p.data.clone(), packet_type: PacketType::Signal })
+                .collect::<Vec<Packet>>();
+            packets.push(Packet { data: JsonValue::Array(vec![JsonValue::Array(vec![JsonValue::Number(Number::from(2))])]), packet_type: PacketType::Divider2 });
+            packets.push(Packet { data: JsonValue::Array(vec![JsonValue::Array(vec![JsonValue::Number(Number::from(6))])]), packet_type: PacketType::Divider6 });
+            
+            packets.sort_by(|a, b| compare_packets(&a.data, &b.data));
+
+            let i2 = packets.iter().zip(1..).find(|(p, _)| p.packet_type == PacketType::Divider2).map(|(_, i)| i).unwrap();
+            let i6 = packets.iter().zip(1..).find(|(p, _)| p.packet_type == PacketType::Divider6).map(|(_, i)| i).unwrap();
+
+            return Ok(i2 * i6);
+        }
+    }
+
+    fn parse_input_as_packets(input: &mut dyn BufRead) -> Result<Vec<Packet>, ParseError> {
+        let lines = read_input(input)?;
+        let mut packets = Vec::new();
+        for pair in split_sections(&number_lines(&lines)) {
+            match pair {
+                [left, right] => {
+                    packets.push(parse_packet(left)?);
+                    packets.push(parse_packet(right)?);
+                },
+                [] => {},
+                [_] => return Err(unexpected_end(pair.last(), "the right packet of the pair")),
+                _ => return Err(pair[2].error_at(1, "a blank line between packet pairs"))
+            }
+        }
+        return Ok(packets);
+    }
+
+    fn parse_packet(line: &Line) -> Result<Packet, ParseError> {
+        let expected = "a packet made of lists and non-negative integers";
+        let data = json::parse(line.text).map_err(|error| match error {
+            json::Error::UnexpectedCharacter { column, .. } => line.error_at(column, expected),
+            _ => line.error_at(line.end_column(), expected)
+        })?;
+        if !data.is_array() || !is_packet_data(&data) {
+            return Err(line.error_at(1, expected));
+        }
+        return Ok(Packet { data, packet_type: PacketType::Signal });
+    }
+
+    fn is_packet_data(value: &JsonValue) -> bool {
+        return match value {
+            JsonValue::Array(elements) => elements.iter().all(is_packet_data),
+            JsonValue::Number(_) => value.as_u32().is_some(),
+            _ => false
+        };
+    }
+
+    pub fn is_packet_pair_in_right_order(left: &Packet, right: &Packet) -> bool {
+        return left.compare(right) == Ordering::Less;
+    }
+
+    fn compare_packets(left: &JsonValue, right: &JsonValue) -> Ordering {
+        if left.is_number() && right.is_number() {
+            return left.as_i32().unwrap().cmp(&right.as_i32().unwrap());
+        }
+        else if left.is_array() && right.is_array() {
+            let mut left_array = left.members();
+            let mut right_array = right.members();
+
+            loop {
+                match (left_array.next(), right_array.next()) {
+                    (None, None) => break,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(left_element), Some(right_element)) => {
+                        let cmp = compare_packets(left_element, right_element);
+                        if cmp != Ordering::Equal {
+                            return cmp;
+                        }
+                    }
+                }
+            }
+        }
+        else if left.is_number() && right.is_array() {
+            return compare_packets(&JsonValue::Array(vec![left.clone()]), right);
+        }
+        else if left.is_array() && right.is_number() {
+            return compare_packets(left, &JsonValue::Array(vec![right.clone()]));
+        }
+
+        return Ordering::Equal;
+    }
+
+    pub struct Packet {
+        packet_type: PacketType,
+        data: JsonValue
+    }
+
+    impl Packet {
+        /// Parses a single packet such as `[1,[2,3]]`.
+        pub fn parse(text: &str) -> Result<Packet, ParseError> {
+            return parse_packet(&Line::new(1, text));
+        }
+
+        pub fn compare(&self, other: &Packet) -> Ordering {
+            return compare_packets(&self.data, &other.data);
+        }
+    }
+
+    #[derive(PartialEq)]
+    enum PacketType {
+        Divider2,
+        Divider6,
+        Signal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day13;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn given_two_integers_when_left_is_lower_than_right_then_inputs_are_in_order() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [1]\n\
+            [2]".as_bytes()).unwrap(), 1);
+    }
+
+    #[test]
+    fn given_two_integers_when_left_is_higher_than_right_then_inputs_are_not_in_order() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [2]\n\
+            [1]".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn given_two_integers_when_left_is_equal_to_right_then_continue_checking_next_part_of_input() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [31]\n\
+            [32]".as_bytes()).unwrap(), 1);
+    }
+
+    #[test]
+    fn given_two_lists_start_comapre_elements_of_each_list() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [[1]]\n\
+            [[2]]".as_bytes()).unwrap(), 1);
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [[2]]\n\
+            [[1]]".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn given_two_lists_when_left_list_runs_out_of_items_then_inputs_are_in_order() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [[1]]\n\
+            [[1, 1]]".as_bytes()).unwrap(), 1);
+    }
+
+    #[test]
+    fn given_two_lists_when_right_list_runs_out_of_items_then_inputs_are_not_in_order() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [[1, 1]]\n\
+            [[1]]".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn given_exactly_one_value_is_integer_convert_the_integer_to_a_list_containing_that_integer() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [[1, 1, 1]]\n\
+            [2]".as_bytes()).unwrap(), 1);
+
+            assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "
+            [2]\n\
+            [[1, 1, 1]]".as_bytes()).unwrap(), 0);
+    }
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut f).unwrap(), 13);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut f).unwrap(), 5506);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day13::decoder_key_for_the_distress_signal(&mut f).unwrap(), 140);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day13::decoder_key_for_the_distress_signal(&mut f).unwrap(), 21756);
+    }
+
+    #[test]
+    fn reports_malformed_packet() {
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "[1,2]\n[1,x]".as_bytes()), Err(ParseError::new(2, 4, "a packet made of lists and non-negative integers")));
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "[1,2]\n[1,\"a\"]".as_bytes()), Err(ParseError::new(2, 1, "a packet made of lists and non-negative integers")));
+        assert_eq!(day13::sum_of_pair_indices_in_the_right_order(&mut "[1]\n[2]\n\n[3]".as_bytes()), Err(ParseError::new(5, 1, "the right packet of the pair")));
+    }
+
+    #[test]
+    fn compares_packets_through_library_api() {
+        let left = day13::Packet::parse("[[1],[2,3,4]]").unwrap();
+        let right = day13::Packet::parse("[[1],4]").unwrap();
+        assert_eq!(left.compare(&right), std::cmp::Ordering::Less);
+        assert!(day13::is_packet_pair_in_right_order(&left, &right));
+        assert!(day13::Packet::parse("[1,").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub paths: usize,
+    pub max_segments: i64,
+    pub max_segment_length: i64,
+    pub spread: i64,
+    pub depth: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { paths: 12, max_segments: 4, max_segment_length: 6, spread: 25, depth: 30 };
+    }
+}
+
+/// `paths` rock paths of straight segments, kept within `spread` columns either side
+/// of the sand source and between rows 1 and `depth`.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let (min_x, max_x) = (500 - params.spread, 500 + params.spread);
+    let paths = (0..params.paths)
+        .map(|_| {
+            let mut point = (rng.between(min_x, max_x), rng.between(1, params.depth));
+            let mut path = vec![point];
+            for _ in 0..rng.between(1, params.max_segments) {
+                let length = rng.between(-params.max_segment_length, params.max_segment_length);
+                point = if rng.chance(1, 2) {
+                    ((point.0 + length).clamp(min_x, max_x), point.1)
+                }
+                else {
+                    (point.0, (point.1 + length).clamp(1, params.depth))
+                };
+                path.push(point);
+            }
+            return path;
+        })
+        .collect::<Vec<Vec<(i64, i64)>>>();
+
+    let input = paths.iter()
+        .map(|path| path.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<String>>().join(" -> "))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let mut rocks = HashSet::new();
+    for path in paths.iter() {
+        for segment in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+    }
+    let lowest_rock = rocks.iter().map(|(_, y)| *y).max().unwrap_or(0);
+
+    return GeneratedInput {
+        input,
+        part1: Some(pour_sand(&rocks, lowest_rock, None).to_string()),
+        part2: Some(pour_sand(&rocks, lowest_rock, Some(lowest_rock + 2)).to_string())
+    };
+}
+
+/// Units of sand at rest once sand falls below `lowest_rock` or, with a floor, once the source is blocked.
+fn pour_sand(rocks: &HashSet<(i64, i64)>, lowest_rock: i64, floor: Option<i64>) -> usize {
+    let mut blocked = rocks.clone();
+    let mut sand_at_rest = 0;
+    while !blocked.contains(&(500, 0)) {
+        let (mut x, mut y) = (500, 0);
+        loop {
+            if floor.is_none() && y > lowest_rock {
+                return sand_at_rest;
+            }
+            let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)].into_iter()
+                .find(|p| !blocked.contains(p) && floor.map(|floor| p.1 < floor).unwrap_or(true));
+            match next {
+                Some(p) => (x, y) = p,
+                None => break
+            }
+        }
+        blocked.insert((x, y));
+        sand_at_rest += 1;
+    }
+    return sand_at_rest;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day14::Day14, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let generated = generator::generate(seed, &generator::Params::default());
+            assert_eq!(generated.check(&Day14), Ok(()), "seed {}", seed);
+        }
+    }
+}
@@ -0,0 +1,377 @@
+pub mod generator;
+
+pub mod day14 {
+    use std::{fmt, io::BufRead};
+    use tracing::{debug, trace};
+    use aoc_common::{geometry::{Bounds, Point}, grid::Grid, input::read_input, parse::{number_lines, Line, ParseError}, render::{Colour, Render}, solution::Solution};
+
+    pub fn how_many_units_of_sand_come_to_rest_before_sand_starts_flowing_into_the_abyss_below(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day14.part1(&Day14.parse(input)?);
+    }
+
+    pub fn how_many_units_of_sand_come_to_rest_before_sand_of_source_becomes_blocked(input: &mut dyn BufRead) -> Result<usize, ParseError> {
+        return Day14.part2(&Day14.parse(input)?);
+    }
+
+    const SAND_SOURCE: Point<usize> = Point::new(500, 0);
+
+    /// The cave once sand flows into the abyss: rock in grey, sand in yellow and the source in red.
+    pub fn picture(input: &mut dyn BufRead) -> Result<Box<dyn Render>, ParseError> {
+        let mut cave = CaveBuilder::new()
+            .with_rocks(Day14.parse(input)?)
+            .with_sand_source(SAND_SOURCE)
+            .build();
+        cave.stabilize();
+        return Ok(Box::new(cave));
+    }
+
+    pub struct Day14;
+
+    impl Solution for Day14 {
+        type Parsed = Vec<Vec<Point<usize>>>;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Vec<Point<usize>>>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, rock_paths: &Vec<Vec<Point<usize>>>) -> Result<usize, ParseError> {
+            let mut cave = CaveBuilder::new()
+                .with_rocks(rock_paths.clone())
+                .with_sand_source(SAND_SOURCE)
+                .build();
+
+            cave.stabilize();
+
+            return Ok(cave.count_sand_at_rest());
+        }
+
+        fn part2(&self, rock_paths: &Vec<Vec<Point<usize>>>) -> Result<usize, ParseError> {
+            let mut cave = CaveBuilder::new()
+                .with_rocks(rock_paths.clone())
+                .with_sand_source(SAND_SOURCE)
+                .with_infinite_horizonal_floor(2)
+                .build();
+
+            cave.stabilize();
+
+            return Ok(cave.count_sand_at_rest());
+        }
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Vec<Point<usize>>>, ParseError> {
+        let lines = read_input(input)?;
+        return number_lines(&lines).iter().map(parse_rock_path).collect();
+    }
+
+    fn parse_rock_path(line: &Line) -> Result<Vec<Point<usize>>, ParseError> {
+        let mut path: Vec<Point<usize>> = Vec::new();
+        for coords in line.text.split(" -> ") {
+            let point = parse_coords(line, coords)?;
+            if let Some(previous) = path.last() {
+                if previous.x != point.x && previous.y != point.y {
+                    return Err(line.error_on(coords, "a point in a straight horizontal or vertical line from the previous one"));
+                }
+            }
+            path.push(point);
+        }
+        return Ok(path);
+    }
+
+    fn parse_coords(line: &Line, coords: &str) -> Result<Point<usize>, ParseError> {
+        let (x, y) = coords.split_once(',').ok_or_else(|| line.error_on(coords, "`x,y` coordinates"))?;
+        return Ok(Point::new(line.parse::<usize>(x, "an x coordinate")?, line.parse::<usize>(y, "a y coordinate")?));
+    }
+
+    /// A slice of the cave, translated so that its leftmost and topmost points are at `(0, 0)`.
+    pub struct Cave {
+        grid: Grid<Object>,
+        sand_source: Point<usize>
+    }
+
+    impl Cave {
+        /// Pours sand until it either flows into the abyss or blocks the source.
+        pub fn stabilize(&mut self) {
+            while let Some(sand_tile) = self.generate_sand_tile() {
+                trace!(x = sand_tile.x, y = sand_tile.y, "sand came to rest");
+            }
+
+            debug!(sand = self.count_sand_at_rest(), cave = %format_args!("\n{}", self), "cave stabilized");
+        }
+
+        /// Drops a single unit of sand, returning where it came to rest, or `None` once sand no longer comes to rest below the source.
+        pub fn generate_sand_tile(&mut self) -> Option<Point<usize>> {
+            let mut sand_tile = self.sand_source;
+
+            loop {
+                match self.analyze_drop_moves(&sand_tile) {
+                    Ok(coords) => {
+                        sand_tile = coords;
+                    },
+                    Err(TileError::Blocked) => {
+                        self.grid.set(sand_tile.x, sand_tile.y, Object::Sand);
+                        break;
+                    },
+                    Err(TileError::Void) => {
+                        return None;
+                    }
+                }
+            }
+
+            if sand_tile == self.sand_source {
+                return None;
+            }
+
+            return Some(sand_tile);
+        }
+
+        fn analyze_drop_moves(&self, coords: &Point<usize>) -> Result<Point<usize>, TileError> {
+            for drop_move in self.drop_moves(coords) {
+                let possible_move = match drop_move {
+                    Ok(coords) => Some(Ok(coords)),
+                    Err(TileError::Blocked) => None,
+                    Err(TileError::Void) => Some(Err(TileError::Void))
+                };
+
+                if let Some(m) = possible_move {
+                    return m;
+                }
+            }
+
+            return Err(TileError::Blocked);
+        }
+
+        fn drop_moves(&self, coords: &Point<usize>) -> Vec<Result<Point<usize>, TileError>> {
+            let drops = [
+                self.get_down_neighbour(coords, 0),
+                self.get_down_neighbour(coords, -1),
+                self.get_down_neighbour(coords, 1)
+            ];
+
+            return drops.iter().map(|d|self.analyze_neighbour(*d)).collect();
+        }
+
+        fn analyze_neighbour(&self, coords: Option<Point<usize>>) -> Result<Point<usize>, TileError> {
+            return match coords {
+                Some(neighbour) => 
+                    if self.is_tile_available(&neighbour) {
+                        Result::Ok(neighbour)
+                    }
+                    else {
+                        Result::Err(TileError::Blocked)
+                    },
+                None => Result::Err(TileError::Void)
+            };
+        }
+
+        fn is_tile_available(&self, coords: &Point<usize>) -> bool {
+            return *self.grid.get_at(coords) == Object::Air;
+        }
+
+        fn get_down_neighbour(&self, coords: &Point<usize>, dx: isize) -> Option<Point<usize>> {
+            let neighbour = Point::new(coords.x.checked_add_signed(dx)?, coords.y + 1);
+            if self.grid.contains(neighbour.x, neighbour.y) {
+                return Some(neighbour);
+            }
+            else {
+                return None;
+            }
+        }
+
+        pub fn count_sand_at_rest(&self) -> usize {
+            return self.grid.find_coords(|object| *object == Object::Sand).len();
+        }
+    }
+
+    #[derive(Debug)]
+    enum TileError {
+        Blocked,
+        Void
+    }
+
+    pub struct CaveBuilder {
+        rock_paths: Vec<Vec<Point<usize>>>,
+        sand_source: Point<usize>,
+        infinite_horizontal_floor_elevation_offset: Option<usize>
+    }
+
+    impl Default for CaveBuilder {
+        fn default() -> CaveBuilder {
+            return CaveBuilder::new();
+        }
+    }
+
+    impl CaveBuilder {
+        pub fn new() -> CaveBuilder {
+            return CaveBuilder{ rock_paths: vec![], sand_source: SAND_SOURCE, infinite_horizontal_floor_elevation_offset: None };
+        }
+
+        pub fn with_rocks(&mut self, rock_paths: Vec<Vec<Point<usize>>>) -> &mut CaveBuilder {
+            self.rock_paths = rock_paths;
+            return self;
+        }
+
+        pub fn with_sand_source(&mut self, sand_source: Point<usize>) -> &mut CaveBuilder {
+            self.sand_source = sand_source;
+            return self;
+        }
+
+        /// Adds a floor `y_offset` below the lowest rock, wide enough for the sand never to fall past it.
+        pub fn with_infinite_horizonal_floor(&mut self, y_offset: usize) -> &mut CaveBuilder {
+            self.infinite_horizontal_floor_elevation_offset = Some(y_offset);
+            return self;
+        }
+
+        pub fn build(&self) -> Cave {
+            let mut bounds = Bounds::enclosing(self.rock_paths.iter().flatten())
+                .map(|bounds| bounds.expanded_to(&self.sand_source))
+                .unwrap_or(Bounds::new(self.sand_source, self.sand_source));
+
+            if let Some(y_offset) = self.infinite_horizontal_floor_elevation_offset {
+                let floor_y = bounds.max.y + y_offset;
+                let height = floor_y - bounds.min.y;
+                bounds = bounds
+                    .expanded_to(&Point::new(self.sand_source.x.saturating_sub(height + 3), floor_y))
+                    .expanded_to(&Point::new(self.sand_source.x + height + 3, floor_y));
+            }
+
+            let translate = |p: &Point<usize>| *p - bounds.min;
+
+            let mut grid = Grid::filled(bounds.width(), bounds.height(), Object::Air);
+
+            for rock_path in self.rock_paths.iter() {
+                for rock_line in rock_path.windows(2) {
+                    for p in rock_line[0].line_to(&rock_line[1]) {
+                        let rock = translate(&p);
+                        grid.set(rock.x, rock.y, Object::Rock);
+                    }
+                }
+            }
+
+            if self.infinite_horizontal_floor_elevation_offset.is_some() {
+                let floor_y = bounds.height() - 1;
+                for p in Point::new(0, floor_y).line_to(&Point::new(bounds.width() - 1, floor_y)) {
+                    grid.set(p.x, p.y, Object::Rock);
+                }
+            }
+
+            let translated_sand_source = translate(&self.sand_source);
+
+            grid.set(translated_sand_source.x, translated_sand_source.y, Object::SourceOfSand);
+
+            return Cave { grid, sand_source: translated_sand_source };
+        }
+    }
+
+    impl fmt::Display for Cave {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let rows = self.grid.rows().iter()
+                .map(|row| row.iter().map(|object| object.to_char()).collect::<String>())
+                .collect::<Vec<String>>();
+            return write!(f, "{}", rows.join("\n"));
+        }
+    }
+
+    impl Render for Cave {
+        fn width(&self) -> usize {
+            return self.grid.width();
+        }
+
+        fn height(&self) -> usize {
+            return self.grid.height();
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            return match self.grid.get(x, y) {
+                Object::Air => Colour::rgb(20, 20, 30),
+                Object::Rock => Colour::rgb(120, 120, 120),
+                Object::Sand => Colour::rgb(230, 200, 90),
+                Object::SourceOfSand => Colour::rgb(220, 30, 30)
+            };
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum Object {
+        Air,
+        Rock,
+        Sand,
+        SourceOfSand
+    }
+
+    impl Object {
+        fn to_char(self) -> char {
+            return match self {
+                Object::Air => '.',
+                Object::Rock => '#',
+                Object::Sand => 'o',
+                Object::SourceOfSand => '+'
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day14;
+    use aoc_common::geometry::Point;
+    use aoc_common::render::Colour;
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_starts_flowing_into_the_abyss_below(&mut f).unwrap(), 24);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_starts_flowing_into_the_abyss_below(&mut f).unwrap(), 665);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_of_source_becomes_blocked(&mut f).unwrap(), 93);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_of_source_becomes_blocked(&mut f).unwrap(), 25434);
+    }
+
+    #[test]
+    fn reports_malformed_rock_path() {
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_starts_flowing_into_the_abyss_below(&mut "498,4 -> 498,6 -> 496;6".as_bytes()), Err(ParseError::new(1, 19, "`x,y` coordinates")));
+        assert_eq!(day14::how_many_units_of_sand_come_to_rest_before_sand_starts_flowing_into_the_abyss_below(&mut "498,4 -> 497,6".as_bytes()), Err(ParseError::new(1, 10, "a point in a straight horizontal or vertical line from the previous one")));
+    }
+
+    #[test]
+    fn pours_sand_through_library_api() {
+        let mut cave = day14::CaveBuilder::new()
+            .with_rocks(vec![
+                vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)],
+                vec![Point::new(503, 4), Point::new(502, 4), Point::new(502, 9), Point::new(494, 9)]
+            ])
+            .with_sand_source(Point::new(500, 0))
+            .build();
+        assert!(cave.generate_sand_tile().is_some());
+        assert_eq!(cave.count_sand_at_rest(), 1);
+        cave.stabilize();
+        assert_eq!(cave.count_sand_at_rest(), 24);
+    }
+
+    #[test]
+    fn renders_cave() {
+        let picture = day14::picture(&mut "499,2 -> 501,2".as_bytes()).unwrap();
+        assert_eq!((picture.width(), picture.height()), (3, 3));
+        assert_eq!(picture.colour(1, 0), Colour::rgb(220, 30, 30));
+        assert_eq!(picture.colour(1, 1), Colour::rgb(230, 200, 90));
+        assert_eq!(picture.colour(0, 2), Colour::rgb(120, 120, 120));
+        assert_eq!(picture.colour(0, 0), Colour::rgb(20, 20, 30));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::generate::{GeneratedInput, Rng};
+
+pub struct Params {
+    pub row: i64,
+    pub max_coord: i64
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        return Params { row: 20, max_coord: 40 };
+    }
+}
+
+struct Sensor {
+    position: (i64, i64),
+    beacon: (i64, i64)
+}
+
+impl Sensor {
+    fn radius(&self) -> i64 {
+        return distance(self.position, self.beacon);
+    }
+
+    fn covers(&self, point: (i64, i64)) -> bool {
+        return distance(self.position, point) <= self.radius();
+    }
+}
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    return (a.0 - b.0).abs() + (a.1 - b.1).abs();
+}
+
+/// Sensors covering every position from 0 to `max_coord` on both axes except one hidden
+/// distress beacon. The answers are for `Day15 { row, min_coord: 0, max_coord }`.
+pub fn generate(seed: u64, params: &Params) -> GeneratedInput {
+    let mut rng = Rng::new(seed);
+    let max = params.max_coord;
+    let hidden = (rng.between(0, max), rng.between(0, max));
+    let mut uncovered = (0..=max).flat_map(|x| (0..=max).map(move |y| (x, y)))
+        .filter(|point| *point != hidden)
+        .collect::<Vec<(i64, i64)>>();
+
+    let mut sensors = Vec::new();
+    while !uncovered.is_empty() {
+        let position = (rng.between(-max / 4, max + max / 4), rng.between(-max / 4, max + max / 4));
+        let radius = distance(position, hidden) - 1;
+        if radius < 1 {
+            continue;
+        }
+        let dx = rng.between(-radius, radius);
+        let dy = (radius - dx.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+        let sensor = Sensor { position, beacon: (position.0 + dx, position.1 + dy) };
+        if uncovered.iter().any(|point| sensor.covers(*point)) {
+            uncovered.retain(|point| !sensor.covers(*point));
+            sensors.push(sensor);
+        }
+    }
+
+    let input = sensors.iter()
+        .map(|s| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}", s.position.0, s.position.1, s.beacon.0, s.beacon.1))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let from = sensors.iter().map(|s| s.position.0 - s.radius()).min().unwrap_or(0);
+    let to = sensors.iter().map(|s| s.position.0 + s.radius()).max().unwrap_or(0);
+    let covered_in_row = (from..=to)
+        .map(|x| (x, params.row))
+        .filter(|point| sensors.iter().all(|s| s.beacon != *point))
+        .filter(|point| sensors.iter().any(|s| s.covers(*point)))
+        .count();
+
+    return GeneratedInput {
+        input,
+        part1: Some(covered_in_row.to_string()),
+        part2: Some((hidden.0 * 4000000 + hidden.1).to_string())
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{day15::Day15, generator};
+
+    #[test]
+    fn generated_inputs_match_reference_answers() {
+        for seed in 0..20 {
+            let params = generator::Params::default();
+            let generated = generator::generate(seed, &params);
+            let day = Day15 { row: params.row as isize, min_coord: 0, max_coord: params.max_coord as isize };
+            assert_eq!(generated.check(&day), Ok(()), "seed {}", seed);
+        }
+    }
+}
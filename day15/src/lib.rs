@@ -0,0 +1,277 @@
+pub mod generator;
+
+pub mod day15 {
+    use std::io::BufRead;
+    use aoc_common::{geometry::{Bounds, Point}, input::read_input, parse::{number_lines, Line, ParseError}, render::{Colour, Render}, solution::Solution};
+    use itertools::Itertools;
+    use regex::Regex;
+
+    pub fn how_many_positions_cannot_contain_a_beacon_in_the_row(input: &mut dyn BufRead, y: isize) -> Result<usize, ParseError> {
+        return Ok(count_positions_that_cannot_contain_a_beacon(&parse_input(input)?, y));
+    }
+
+    pub fn count_positions_that_cannot_contain_a_beacon(sensors: &[Sensor], y: isize) -> usize {
+        let known_beacons = sensors.iter()
+            .map(|s| s.closest_beacon_coords)
+            .unique()
+            .collect::<Vec<Point<isize>>>();
+
+        let covered_corners = sensors.iter()
+            .flat_map(|s| {
+                let distance = s.get_manhattan_distance_from_closest_beacon();
+                [s.coords - Point::new(distance, distance), s.coords + Point::new(distance, distance)]
+            })
+            .collect::<Vec<Point<isize>>>();
+
+        let bounds = match Bounds::enclosing(&covered_corners) {
+            Some(bounds) => bounds,
+            None => return 0
+        };
+
+        let reserved = (bounds.min.x..=bounds.max.x).map(|x| Point::new(x, y))
+            .filter(|coords| !known_beacons.contains(coords))
+            .filter(|coords| is_sensors_area(sensors, coords))
+            .collect::<Vec<Point<isize>>>();
+        
+        return reserved.len();
+    }
+
+    pub fn distress_beacon_tuning_frequency(input: &mut dyn BufRead, min_coord: isize, max_coord: isize) -> Result<Option<isize>, ParseError> {
+        return Ok(find_distress_beacon_tuning_frequency(&parse_input(input)?, min_coord, max_coord));
+    }
+
+    /// Tuning frequency of the only position between `min_coord` and `max_coord` on both axes not covered by any sensor.
+    pub fn find_distress_beacon_tuning_frequency(sensors: &[Sensor], min_coord: isize, max_coord: isize) -> Option<isize> {
+        for y in min_coord..=max_coord {
+            if let Some(beacon) = find_potential_beacons(sensors, y, min_coord, max_coord) {
+                return Some(beacon.x * 4000000 + beacon.y);
+            }
+        }
+
+        return None;
+    }
+
+    /// Longest side of the coverage picture in cells; larger areas are sampled.
+    const PICTURE_SIZE: usize = 400;
+
+    /// The area covered by sensors in light blue, sensors in red and their closest beacons in dark blue.
+    pub fn picture(input: &mut dyn BufRead) -> Result<Box<dyn Render>, ParseError> {
+        return Ok(Box::new(CoverageMap::new(parse_input(input)?, PICTURE_SIZE)));
+    }
+
+    pub struct Day15 {
+        pub row: isize,
+        pub min_coord: isize,
+        pub max_coord: isize
+    }
+
+    impl Default for Day15 {
+        fn default() -> Day15 {
+            return Day15 { row: 2000000, min_coord: 0, max_coord: 4000000 };
+        }
+    }
+
+    impl Solution for Day15 {
+        type Parsed = Vec<Sensor>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(&self, input: &mut dyn BufRead) -> Result<Vec<Sensor>, ParseError> {
+            return parse_input(input);
+        }
+
+        fn part1(&self, sensors: &Vec<Sensor>) -> Result<usize, ParseError> {
+            return Ok(count_positions_that_cannot_contain_a_beacon(sensors, self.row));
+        }
+
+        fn part2(&self, sensors: &Vec<Sensor>) -> Result<String, ParseError> {
+            return Ok(find_distress_beacon_tuning_frequency(sensors, self.min_coord, self.max_coord)
+                .map(|frequency| frequency.to_string())
+                .unwrap_or_else(|| "no distress beacon found".to_string()));
+        }
+    }
+
+    fn is_sensors_area(sensors: &[Sensor], coords: &Point<isize>) -> bool {
+        return sensors.iter().any(|s| s.is_in_covered_area(coords));
+    }
+
+    fn find_potential_beacons(sensors: &[Sensor], y: isize, min_x: isize, max_x: isize) -> Option<Point<isize>> {
+        let mut covered_regions = sensors.iter()
+            .filter(|s| (s.coords.y - y).abs() <= s.get_manhattan_distance_from_closest_beacon())
+            .map(|s| {
+                let half_width = s.get_manhattan_distance_from_closest_beacon() - (s.coords.y - y).abs();
+                return (s.coords.x - half_width, s.coords.x + half_width);
+            })
+            .collect::<Vec<(isize, isize)>>();
+
+        covered_regions.sort_by_key(|region| region.0);
+
+        let mut first_uncovered = min_x;
+
+        for (from, to) in covered_regions.iter() {
+            if *from > first_uncovered {
+                break;
+            }
+            first_uncovered = first_uncovered.max(to + 1);
+        }
+
+        return if first_uncovered <= max_x { Some(Point::new(first_uncovered, y)) } else { None };
+    }
+
+    fn parse_input(input: &mut dyn BufRead) -> Result<Vec<Sensor>, ParseError> {
+        let lines = read_input(input)?;
+        let re = Regex::new(r"^Sensor at x=([-]?\d+), y=([-]?\d+): closest beacon is at x=([-]?\d+), y=([-]?\d+)$").unwrap();
+        return number_lines(&lines).iter().map(|line| parse_sensor(&re, line)).collect();
+    }
+
+    fn parse_sensor(re: &Regex, line: &Line) -> Result<Sensor, ParseError> {
+        let captures = re.captures(line.text)
+            .ok_or_else(|| line.error_at(1, "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`"))?;
+        let coordinate = |i: usize| line.parse::<isize>(captures.get(i).unwrap().as_str(), "a coordinate");
+        return Ok(Sensor::new(Point::new(coordinate(1)?, coordinate(2)?), Point::new(coordinate(3)?, coordinate(4)?)));
+    }
+
+    /// Sensor coverage scaled down so that one cell stands for a `step` by `step` square of positions.
+    pub struct CoverageMap {
+        sensors: Vec<Sensor>,
+        bounds: Bounds<isize>,
+        step: isize
+    }
+
+    impl CoverageMap {
+        pub fn new(sensors: Vec<Sensor>, max_cells: usize) -> CoverageMap {
+            let covered_corners = sensors.iter()
+                .flat_map(|s| {
+                    let distance = s.get_manhattan_distance_from_closest_beacon();
+                    [s.coords - Point::new(distance, distance), s.coords + Point::new(distance, distance)]
+                })
+                .collect::<Vec<Point<isize>>>();
+            let bounds = Bounds::enclosing(&covered_corners).unwrap_or(Bounds::new(Point::new(0, 0), Point::new(0, 0)));
+            let max_cells = max_cells.max(1) as isize;
+            let step = (bounds.width().max(bounds.height()) + max_cells - 1) / max_cells;
+            return CoverageMap { sensors, bounds, step };
+        }
+
+        fn cell(&self, x: usize, y: usize) -> Bounds<isize> {
+            let min = self.bounds.min + Point::new(x as isize * self.step, y as isize * self.step);
+            return Bounds::new(min, min + Point::new(self.step - 1, self.step - 1));
+        }
+    }
+
+    impl Render for CoverageMap {
+        fn width(&self) -> usize {
+            return ((self.bounds.width() + self.step - 1) / self.step) as usize;
+        }
+
+        fn height(&self) -> usize {
+            return ((self.bounds.height() + self.step - 1) / self.step) as usize;
+        }
+
+        fn colour(&self, x: usize, y: usize) -> Colour {
+            let cell = self.cell(x, y);
+            if self.sensors.iter().any(|s| cell.contains(&s.coords)) {
+                return Colour::rgb(220, 30, 30);
+            }
+            if self.sensors.iter().any(|s| cell.contains(&s.closest_beacon_coords)) {
+                return Colour::rgb(30, 60, 200);
+            }
+            return if is_sensors_area(&self.sensors, &cell.min) { Colour::rgb(170, 210, 240) } else { Colour::rgb(20, 20, 30) };
+        }
+    }
+
+    pub struct Sensor {
+        coords: Point<isize>,
+        closest_beacon_coords: Point<isize>
+    }
+
+    impl Sensor {
+        pub fn new(coords: Point<isize>, closest_beacon_coords: Point<isize>) -> Sensor {
+            return Sensor { coords, closest_beacon_coords };
+        }
+
+        pub fn get_coords(&self) -> Point<isize> {
+            return self.coords;
+        }
+
+        pub fn get_closest_beacon_coords(&self) -> Point<isize> {
+            return self.closest_beacon_coords;
+        }
+
+        pub fn get_manhattan_distance_from_closest_beacon(&self) -> isize {
+            return self.get_manhattan_distance_to_coords(&self.closest_beacon_coords);
+        }
+
+        /// Whether `coords` is at least as close to the sensor as its closest beacon.
+        pub fn is_in_covered_area(&self, coords: &Point<isize>) -> bool {
+            return self.get_manhattan_distance_to_coords(coords) <= self.get_manhattan_distance_from_closest_beacon();
+        }
+
+        fn get_manhattan_distance_to_coords(&self, coords: &Point<isize>) -> isize {
+            return self.coords.manhattan_distance(coords);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::day15;
+    use aoc_common::geometry::Point;
+    use aoc_common::render::{Colour, Render};
+    use aoc_common::parse::ParseError;
+    use std::{fs::File, io::BufReader};
+
+    #[test]
+    fn part1_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day15::how_many_positions_cannot_contain_a_beacon_in_the_row(&mut f, 10).unwrap(), 26);
+    }
+
+    #[test]
+    fn part1_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day15::how_many_positions_cannot_contain_a_beacon_in_the_row(&mut f, 2000000).unwrap(), 6078701);
+    }
+
+    #[test]
+    fn part2_sample_input() {
+        let mut f = BufReader::new(File::open("./sample.input").unwrap());
+        assert_eq!(day15::distress_beacon_tuning_frequency(&mut f, 0, 20).unwrap().unwrap(), 56000011);
+    }
+
+    #[test]
+    fn part2_day_input() {
+        let mut f = BufReader::new(File::open("./day.input").unwrap());
+        assert_eq!(day15::distress_beacon_tuning_frequency(&mut f, 0, 4000000).unwrap().unwrap(), 12567351400528);
+    }
+
+    #[test]
+    fn reports_malformed_sensor() {
+        assert_eq!(day15::how_many_positions_cannot_contain_a_beacon_in_the_row(&mut "Sensor at x=2, y=18: closest beacon at x=-2, y=15".as_bytes(), 10),
+            Err(ParseError::new(1, 1, "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`")));
+        assert_eq!(day15::how_many_positions_cannot_contain_a_beacon_in_the_row(&mut "Sensor at x=99999999999999999999, y=18: closest beacon is at x=-2, y=15".as_bytes(), 10),
+            Err(ParseError::new(1, 13, "a coordinate")));
+    }
+
+    #[test]
+    fn queries_sensors_through_library_api() {
+        let sensor = day15::Sensor::new(Point::new(8, 7), Point::new(2, 10));
+        assert_eq!(sensor.get_manhattan_distance_from_closest_beacon(), 9);
+        assert!(sensor.is_in_covered_area(&Point::new(8, -2)));
+        assert!(!sensor.is_in_covered_area(&Point::new(8, -3)));
+        assert_eq!(day15::count_positions_that_cannot_contain_a_beacon(&[sensor], 10), 12);
+    }
+
+    #[test]
+    fn renders_sampled_coverage() {
+        let input = "Sensor at x=4, y=4: closest beacon is at x=4, y=6";
+        let picture = day15::picture(&mut input.as_bytes()).unwrap();
+        assert_eq!((picture.width(), picture.height()), (5, 5));
+        assert_eq!(picture.colour(2, 2), Colour::rgb(220, 30, 30));
+        assert_eq!(picture.colour(2, 4), Colour::rgb(30, 60, 200));
+        assert_eq!(picture.colour(1, 2), Colour::rgb(170, 210, 240));
+        assert_eq!(picture.colour(0, 0), Colour::rgb(20, 20, 30));
+
+        let sampled = day15::CoverageMap::new(vec![day15::Sensor::new(Point::new(0, 0), Point::new(0, 10))], 7);
+        assert_eq!((sampled.width(), sampled.height()), (7, 7));
+    }
+}